@@ -2,7 +2,25 @@
 pub(crate) struct Config {
 	pub(crate) ssh: Ssh,
 
+	#[serde(default)]
+	pub(crate) disks: Filter,
+
+	#[serde(default)]
+	pub(crate) filesystems: Filesystems,
+
+	#[serde(default)]
+	pub(crate) interfaces: Interfaces,
+
+	pub(crate) ipmi: Option<Ipmi>,
+
 	pub(crate) services: Option<Services>,
+
+	pub(crate) state_holders: Option<StateHolders>,
+
+	#[serde(default)]
+	pub(crate) temperatures: Temperatures,
+
+	pub(crate) traffic: Option<Traffic>,
 }
 
 impl Config {
@@ -16,6 +34,141 @@ impl Config {
 	}
 }
 
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct Filesystems {
+	#[serde(default = "Filesystems::default_types")]
+	pub(crate) types: Filter,
+
+	#[serde(default)]
+	pub(crate) mount_points: Filter,
+
+	#[serde(default)]
+	pub(crate) inodes: bool,
+
+	#[serde(default)]
+	pub(crate) merge_zfs_datasets: bool,
+
+	#[serde(default = "Filesystems::default_time_to_full_warning_hours")]
+	pub(crate) time_to_full_warning_hours: u64,
+}
+
+impl Filesystems {
+	fn default_types() -> Filter {
+		Filter {
+			include: vec!["tmpfs".to_owned(), "ufs".to_owned(), "zfs".to_owned()],
+			exclude: vec![],
+		}
+	}
+
+	fn default_time_to_full_warning_hours() -> u64 {
+		24
+	}
+}
+
+impl Default for Filesystems {
+	fn default() -> Self {
+		Filesystems {
+			types: Filesystems::default_types(),
+			mount_points: Default::default(),
+			inodes: false,
+			merge_zfs_datasets: false,
+			time_to_full_warning_hours: Filesystems::default_time_to_full_warning_hours(),
+		}
+	}
+}
+
+#[derive(Default, serde::Deserialize)]
+pub(crate) struct Interfaces {
+	#[serde(default)]
+	pub(crate) names: InterfaceNames,
+
+	#[serde(default)]
+	pub(crate) disabled: DisabledInterfaces,
+
+	#[serde(default)]
+	pub(crate) link_local_addresses: bool,
+}
+
+#[derive(Clone, Copy, Default, serde::Deserialize)]
+pub(crate) enum DisabledInterfaces {
+	#[default]
+	#[serde(rename = "dim")]
+	Dim,
+
+	#[serde(rename = "hide")]
+	Hide,
+}
+
+#[derive(Clone, Copy, Default, serde::Deserialize)]
+pub(crate) enum InterfaceNames {
+	// `igb0`
+	#[default]
+	#[serde(rename = "device")]
+	Device,
+
+	// `LAN`
+	#[serde(rename = "description")]
+	Description,
+
+	// `LAN (igb0)`
+	#[serde(rename = "both")]
+	Both,
+}
+
+impl InterfaceNames {
+	pub(crate) fn display_name(self, r#if: &str, description: &crate::opnconfig::InterfaceDescription) -> String {
+		match self {
+			InterfaceNames::Device => r#if.to_owned(),
+			InterfaceNames::Description => description.display_name().into_owned(),
+			InterfaceNames::Both => format!("{} ({if})", description.display_name()),
+		}
+	}
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct Ipmi {
+	#[serde(default)]
+	pub(crate) sensors: Filter,
+
+	#[serde(default = "Ipmi::default_refresh_interval")]
+	pub(crate) refresh_interval: u64,
+}
+
+impl Ipmi {
+	fn default_refresh_interval() -> u64 {
+		10
+	}
+}
+
+// A list of glob patterns to include and exclude. `*` matches any sequence of characters and `?` matches any single character.
+//
+// An empty include list includes everything. Exclusions take priority over inclusions.
+#[derive(Debug, Default, serde::Deserialize)]
+pub(crate) struct Filter {
+	#[serde(default)]
+	pub(crate) include: Vec<String>,
+
+	#[serde(default)]
+	pub(crate) exclude: Vec<String>,
+}
+
+impl Filter {
+	pub(crate) fn matches(&self, s: &str) -> bool {
+		(self.include.is_empty() || self.include.iter().any(|pattern| glob_matches(pattern.as_bytes(), s.as_bytes()))) &&
+		!self.exclude.iter().any(|pattern| glob_matches(pattern.as_bytes(), s.as_bytes()))
+	}
+}
+
+fn glob_matches(pattern: &[u8], s: &[u8]) -> bool {
+	match (pattern.split_first(), s.split_first()) {
+		(None, None) => true,
+		(Some((b'*', pattern_rest)), _) => glob_matches(pattern_rest, s) || s.split_first().is_some_and(|(_, s_rest)| glob_matches(pattern, s_rest)),
+		(Some((b'?', pattern_rest)), Some((_, s_rest))) => glob_matches(pattern_rest, s_rest),
+		(Some((p, pattern_rest)), Some((c, s_rest))) => p == c && glob_matches(pattern_rest, s_rest),
+		_ => false,
+	}
+}
+
 #[derive(serde::Deserialize)]
 pub(crate) struct Services {
 	#[serde(default)]
@@ -25,6 +178,96 @@ pub(crate) struct Services {
 	pub(crate) custom: Vec<CustomService>,
 }
 
+#[derive(serde::Deserialize)]
+pub(crate) struct StateHolders {
+	#[serde(default = "StateHolders::default_count")]
+	pub(crate) count: usize,
+
+	#[serde(default = "StateHolders::default_refresh_interval")]
+	pub(crate) refresh_interval: u64,
+}
+
+impl StateHolders {
+	fn default_count() -> usize {
+		5
+	}
+
+	fn default_refresh_interval() -> u64 {
+		10
+	}
+}
+
+#[derive(serde::Deserialize)]
+pub(crate) struct Temperatures {
+	#[serde(default = "Temperatures::default_sysctls")]
+	pub(crate) sysctls: Filter,
+
+	#[serde(default)]
+	pub(crate) aliases: std::collections::BTreeMap<String, String>,
+
+	#[serde(default)]
+	pub(crate) fahrenheit: bool,
+}
+
+impl Temperatures {
+	fn default_sysctls() -> Filter {
+		Filter {
+			include: vec!["*temperature*".to_owned()],
+			exclude: vec![],
+		}
+	}
+
+	pub(crate) fn display_name<'a>(&'a self, name: &'a str) -> &'a str {
+		self.aliases.get(name).map_or(name, AsRef::as_ref)
+	}
+}
+
+impl Default for Temperatures {
+	fn default() -> Self {
+		Temperatures {
+			sysctls: Temperatures::default_sysctls(),
+			aliases: Default::default(),
+			fahrenheit: false,
+		}
+	}
+}
+
+#[derive(serde::Deserialize)]
+pub(crate) struct Traffic {
+	#[serde(default = "Traffic::default_billing_day")]
+	pub(crate) billing_day: u32,
+
+	pub(crate) interfaces: std::collections::BTreeMap<String, TrafficInterface>,
+}
+
+impl Traffic {
+	fn default_billing_day() -> u32 {
+		1
+	}
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct TrafficInterface {
+	// Monthly cap for received and sent bytes combined, in GB
+	pub(crate) cap: Option<f32>,
+
+	#[serde(default = "TrafficInterface::default_warning")]
+	pub(crate) warning: f32,
+
+	#[serde(default = "TrafficInterface::default_critical")]
+	pub(crate) critical: f32,
+}
+
+impl TrafficInterface {
+	fn default_warning() -> f32 {
+		80.
+	}
+
+	fn default_critical() -> f32 {
+		95.
+	}
+}
+
 #[derive(serde::Deserialize)]
 pub(crate) struct Ssh {
 	pub(crate) hostname: String,
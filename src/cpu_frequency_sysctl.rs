@@ -0,0 +1,29 @@
+#[derive(Debug)]
+pub(crate) struct CpuFrequencySysctl {
+	pub(crate) name: String,
+	cpu_index: usize,
+	pub(crate) value: crate::c_int,
+}
+
+impl CpuFrequencySysctl {
+	pub(crate) fn get_all(sysctl_names: &[String]) -> Box<[Self]> {
+		let mut result: Box<[_]> =
+			sysctl_names.iter()
+			.filter_map(|sysctl_name| {
+				let cpu_index = sysctl_name.strip_prefix("dev.cpu.")?.strip_suffix(".freq")?.parse().ok()?;
+				Some(CpuFrequencySysctl {
+					name: sysctl_name.clone(),
+					cpu_index,
+					value: 0,
+				})
+			})
+			.collect();
+		result.sort_by_key(|cpu_frequency_sysctl| cpu_frequency_sysctl.cpu_index);
+		result
+	}
+
+	pub(crate) fn update(&mut self, reader: &mut impl std::io::Read) -> Result<(), crate::Error> {
+		self.value = crate::Parse::parse(reader)?;
+		Ok(())
+	}
+}
@@ -0,0 +1,22 @@
+#[derive(Debug)]
+pub(crate) struct CpuInfo {
+	pub(crate) num_cpus: crate::c_int,
+	pub(crate) model: String,
+}
+
+impl crate::Parse for CpuInfo {
+	fn parse<R>(reader: &mut R) -> std::io::Result<Self> where R: std::io::Read {
+		let num_cpus = crate::Parse::parse(reader)?;
+
+		// hw.model is a NUL-terminated string that runs until the end of the output
+		let mut model = vec![];
+		let _ = std::io::Read::read_to_end(reader, &mut model)?;
+		let model = String::from_utf8(model).map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+		let model = model.trim_end_matches('\0').trim().to_owned();
+
+		Ok(CpuInfo {
+			num_cpus,
+			model,
+		})
+	}
+}
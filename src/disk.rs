@@ -1,52 +1,190 @@
+pub(crate) type Counter = fn(&crate::ssh_exec::smartctl_a::Health) -> Option<u64>;
+
 #[derive(Debug)]
 pub(crate) struct Disk {
 	pub(crate) name: String,
-	pub(crate) serial_number: String,
-	smart_status_exec: crate::ssh_exec::smartctl_a::Exec,
-	pub(crate) smart_passed: bool,
-	pub(crate) temperature: crate::c_uint,
+	pub(crate) serial_number: Option<String>,
+
+	// None for disks that don't support SMART, like VirtIO disks.
+	smart_status_exec: Option<crate::ssh_exec::smartctl_a::Exec>,
+
+	// These are None if the disk doesn't support SMART or smartctl could not read them.
+	pub(crate) smart_passed: Option<bool>,
+	pub(crate) temperature: Option<crate::c_uint>,
+
+	pub(crate) health: Option<crate::ssh_exec::smartctl_a::Health>,
+	pub(crate) self_test: Option<crate::ssh_exec::smartctl_a::SelfTest>,
+
+	// The health counters from the previous refresh, to detect counters that increased since then.
+	health_previous: Option<crate::ssh_exec::smartctl_a::Health>,
+
+	io_statistics_previous: Option<crate::ssh_exec::iostat_ix::Statistics>,
+	io_statistics: Option<crate::ssh_exec::iostat_ix::Statistics>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct IoRates {
+	pub(crate) read_bytes_per_sec: f32,
+	pub(crate) written_bytes_per_sec: f32,
+	pub(crate) reads_per_sec: f32,
+	pub(crate) writes_per_sec: f32,
+	pub(crate) busy_percent: f32,
+
+	// None if there were no operations to measure
+	pub(crate) latency: Option<std::time::Duration>,
+}
+
+#[derive(Debug)]
+enum DiskKind {
+	// Disks that smartctl can query directly through their own device node, like ATA and SCSI disks.
+	Smart,
+
+	// NVMe namespaces. smartctl needs to query the controller's device node instead.
+	Nvme { controller: String },
+
+	// Disks that exist but don't support SMART, like VirtIO and Xen disks in VMs.
+	NoSmart,
+
+	// Disks that aren't worth showing at all, like CD drives and memory disks.
+	Ignored,
 }
 
 impl Disk {
-	pub(crate) fn get_all(session: &ssh2::Session) -> Result<Box<[Self]>, crate::Error> {
+	pub(crate) fn get_all(filter: &crate::config::Filter, session: &ssh2::Session) -> Result<Box<[Self]>, crate::Error> {
 		let disk_names = crate::ssh_exec::sysctl_kern_disks::run(session)?;
-		let result: Result<Box<[_]>, crate::Error> =
+		let result: Result<Vec<_>, crate::Error> =
 			disk_names.split(' ')
-			.filter_map(|disk_name| {
-				if disk_name.is_empty() {
-					return None;
-				}
-
-				let name = disk_name.to_owned();
-				Some(Disk::new(name, session))
-			})
+			.filter(|disk_name| !disk_name.is_empty() && filter.matches(disk_name))
+			.map(|disk_name| Disk::new(disk_name.to_owned(), session))
 			.collect();
-		let mut result = result?;
+		let mut result: Box<[_]> = result?.into_iter().flatten().collect();
 		result.sort_by(|disk1, disk2| disk1.name.cmp(&disk2.name));
 		Ok(result)
 	}
 
-	fn new(name: String, session: &ssh2::Session) -> Result<Self, crate::Error> {
-		let serial_number = crate::ssh_exec::smartctl_i::get_serial_number(&name, session)?;
+	fn new(name: String, session: &ssh2::Session) -> Result<Option<Self>, crate::Error> {
+		let smart_device = match DiskKind::classify(&name, session)? {
+			DiskKind::Smart => Some(name.clone()),
+			DiskKind::Nvme { controller } => Some(controller),
+			DiskKind::NoSmart => None,
+			DiskKind::Ignored => return Ok(None),
+		};
+
+		let serial_number = match &smart_device {
+			Some(smart_device) => crate::ssh_exec::smartctl_i::get_serial_number(smart_device, session)?,
+			None => None,
+		};
 
-		let smart_status_exec = crate::ssh_exec::smartctl_a::Exec::new(&name);
+		let smart_status_exec = smart_device.as_deref().map(crate::ssh_exec::smartctl_a::Exec::new);
 
-		Ok(Disk {
+		Ok(Some(Disk {
 			name,
 			serial_number,
 			smart_status_exec,
-			smart_passed: false,
-			temperature: 0,
-		})
+			smart_passed: None,
+			temperature: None,
+			health: None,
+			self_test: None,
+			health_previous: None,
+
+			io_statistics_previous: None,
+			io_statistics: None,
+		}))
 	}
 }
 
 impl Disk {
+	pub(crate) fn has_smart(&self) -> bool {
+		self.smart_status_exec.is_some()
+	}
+
 	pub(crate) fn update(&mut self, session: &ssh2::Session) -> Result<(), crate::Error> {
-		let (passed, current) = self.smart_status_exec.run(session)?;
+		let Some(smart_status_exec) = &self.smart_status_exec else { return Ok(()); };
+
+		let crate::ssh_exec::smartctl_a::Smart { passed, temperature, health, self_test } = smart_status_exec.run(session)?;
 		self.smart_passed = passed;
-		self.temperature = current;
+		self.temperature = temperature;
+		self.health_previous = self.health.replace(health);
+		self.self_test = self_test;
 		Ok(())
 	}
+
+	pub(crate) fn update_io_all(disks: &mut [Self], session: &ssh2::Session) -> Result<(), crate::Error> {
+		for disk in &mut *disks {
+			disk.io_statistics_previous = disk.io_statistics.take();
+		}
+
+		for statistics in crate::ssh_exec::iostat_ix::run(session) {
+			let (name, statistics) = statistics?;
+			if let Some(disk) = disks.iter_mut().find(|disk| disk.name == name) {
+				disk.io_statistics = Some(statistics);
+			}
+		}
+
+		Ok(())
+	}
+
+	pub(crate) fn io_rates(&self, time_since_previous: std::time::Duration) -> Option<IoRates> {
+		let previous = self.io_statistics_previous?;
+		let current = self.io_statistics?;
+
+		let time_since_previous = time_since_previous.as_secs_f64();
+
+		let reads = current.reads - previous.reads;
+		let writes = current.writes - previous.writes;
+		let service_time_secs = current.service_time_secs - previous.service_time_secs;
+
+		let latency =
+			if reads + writes > 0. {
+				Some(std::time::Duration::from_secs_f64((service_time_secs / (reads + writes)).max(0.)))
+			}
+			else {
+				None
+			};
+
+		#[allow(clippy::cast_possible_truncation)]
+		Some(IoRates {
+			read_bytes_per_sec: ((current.kib_read - previous.kib_read) * 1024. / time_since_previous) as f32,
+			written_bytes_per_sec: ((current.kib_written - previous.kib_written) * 1024. / time_since_previous) as f32,
+			reads_per_sec: (reads / time_since_previous) as f32,
+			writes_per_sec: (writes / time_since_previous) as f32,
+			busy_percent: ((current.busy_time_secs - previous.busy_time_secs) * 100. / time_since_previous) as f32,
+			latency,
+		})
+	}
+
+	// Returns true if the given error counter increased since the previous refresh.
+	pub(crate) fn increased(&self, counter: Counter) -> bool {
+		let Some(previous) = self.health_previous.as_ref().and_then(counter) else { return false; };
+		self.health.as_ref().and_then(counter).is_some_and(|current| current > previous)
+	}
 }
 
+impl DiskKind {
+	fn classify(name: &str, session: &ssh2::Session) -> Result<Self, crate::Error> {
+		let unit_start = name.find(|c: char| c.is_ascii_digit()).unwrap_or(name.len());
+		let (driver, unit) = name.split_at(unit_start);
+
+		let kind = match driver {
+			// nvd(4) disks are newbus children of their nvme(4) controller.
+			// If the controller can't be found, the disk is still shown, just without SMART.
+			"nvd" => match crate::ssh_exec::sysctl_nvd_parent::run(unit, session)? {
+				Some(controller) => DiskKind::Nvme { controller },
+				None => DiskKind::NoSmart,
+			},
+
+			// nda(4) disks are CAM peripherals without a newbus parent, so their controller is found from the CAM bus they're on.
+			"nda" => match crate::ssh_exec::camcontrol_devlist_v::get_controller(name, session)? {
+				Some(controller) if controller.starts_with("nvme") => DiskKind::Nvme { controller },
+				_ => DiskKind::NoSmart,
+			},
+
+			"vtbd" | "xbd" | "mmcsd" | "sdda" => DiskKind::NoSmart,
+
+			"cd" | "md" => DiskKind::Ignored,
+
+			_ => DiskKind::Smart,
+		};
+		Ok(kind)
+	}
+}
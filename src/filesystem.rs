@@ -0,0 +1,135 @@
+// The growth rate is calculated over this window, so that it isn't thrown off by short bursts of writes and deletes.
+const GROWTH_RATE_WINDOW_SECS: u64 = 15 * 60;
+
+// The growth rate is not shown until the samples cover at least this long, so that a burst of writes right after startup
+// isn't extrapolated into a huge rate.
+const GROWTH_RATE_MIN_SPAN_SECS: u64 = 5 * 60;
+
+#[derive(Debug)]
+pub(crate) struct Filesystems {
+	config: crate::config::Filesystems,
+	inner: Vec<Filesystem>,
+
+	// Samples of used blocks for each filesystem within the last GROWTH_RATE_WINDOW_SECS, oldest first.
+	history: std::collections::BTreeMap<String, std::collections::VecDeque<(std::time::Instant, u64)>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Filesystem {
+	// The mount point, or the pool name if ZFS datasets are merged.
+	pub(crate) name: String,
+	pub(crate) total_blocks: u64,
+	pub(crate) used_blocks: u64,
+	pub(crate) available_blocks: u64,
+	pub(crate) total_inodes: u64,
+	pub(crate) used_inodes: u64,
+
+	// In blocks per second. None until there are at least two samples.
+	pub(crate) growth_rate: Option<f32>,
+}
+
+impl Filesystem {
+	// None if the filesystem isn't growing.
+	pub(crate) fn time_to_full(&self) -> Option<std::time::Duration> {
+		let growth_rate = self.growth_rate.filter(|&growth_rate| growth_rate > 0.)?;
+		Some(std::time::Duration::from_secs_f32(self.available_blocks as f32 / growth_rate))
+	}
+}
+
+impl Filesystems {
+	pub(crate) fn new(config: crate::config::Filesystems) -> Self {
+		Filesystems {
+			config,
+			inner: vec![],
+			history: Default::default(),
+		}
+	}
+
+	pub(crate) fn time_to_full_warning(&self) -> std::time::Duration {
+		std::time::Duration::from_secs(self.config.time_to_full_warning_hours * 60 * 60)
+	}
+
+	pub(crate) fn show_inodes(&self) -> bool {
+		self.config.inodes
+	}
+
+	pub(crate) fn iter(&self) -> impl ExactSizeIterator<Item = &'_ Filesystem> {
+		self.inner.iter()
+	}
+
+	pub(crate) fn update(&mut self, session: &ssh2::Session) -> Result<(), crate::Error> {
+		self.inner.clear();
+
+		// ZFS datasets in the same pool share the pool's free space, so when they're merged the pool's size is
+		// the sum of the space used by each dataset plus the (common) free space.
+		let mut zfs_pools: std::collections::BTreeMap<String, (u64, u64, u64, u64)> = Default::default();
+
+		for filesystem in crate::ssh_exec::df::get_filesystems(session)? {
+			if !self.config.types.matches(&filesystem.r#type) || !self.config.mount_points.matches(&filesystem.mounted_on) {
+				continue;
+			}
+
+			if self.config.merge_zfs_datasets && filesystem.r#type == "zfs" {
+				let pool_name = filesystem.name.split('/').next().unwrap_or(&filesystem.name);
+				let (used_blocks, available_blocks, used_inodes, free_inodes) = zfs_pools.entry(pool_name.to_owned()).or_default();
+				*used_blocks += filesystem.used_blocks;
+				*available_blocks = std::cmp::max(*available_blocks, filesystem.available_blocks);
+				*used_inodes += filesystem.used_inodes;
+				*free_inodes = std::cmp::max(*free_inodes, filesystem.free_inodes);
+				continue;
+			}
+
+			self.inner.push(Filesystem {
+				name: filesystem.mounted_on,
+				total_blocks: filesystem.total_blocks,
+				used_blocks: filesystem.used_blocks,
+				available_blocks: filesystem.available_blocks,
+				total_inodes: filesystem.used_inodes + filesystem.free_inodes,
+				used_inodes: filesystem.used_inodes,
+				growth_rate: None,
+			});
+		}
+
+		self.inner.extend(zfs_pools.into_iter().map(|(name, (used_blocks, available_blocks, used_inodes, free_inodes))| Filesystem {
+			name,
+			total_blocks: used_blocks + available_blocks,
+			used_blocks,
+			available_blocks,
+			total_inodes: used_inodes + free_inodes,
+			used_inodes,
+			growth_rate: None,
+		}));
+
+		self.inner.sort_by(|fs1, fs2|
+			fs1.name.cmp(&fs2.name)
+			.then_with(|| fs1.total_blocks.cmp(&fs2.total_blocks))
+			.then_with(|| fs1.used_blocks.cmp(&fs2.used_blocks))
+		);
+
+		let now = std::time::Instant::now();
+
+		self.history.retain(|name, _| self.inner.iter().any(|filesystem| filesystem.name == *name));
+
+		for filesystem in &mut self.inner {
+			let history = self.history.entry(filesystem.name.clone()).or_default();
+
+			while history.front().is_some_and(|&(sampled_at, _)| now.duration_since(sampled_at).as_secs() > GROWTH_RATE_WINDOW_SECS) {
+				let _ = history.pop_front();
+			}
+
+			history.push_back((now, filesystem.used_blocks));
+
+			let &(oldest_sampled_at, oldest_used_blocks) = history.front().expect("just pushed a sample");
+			let elapsed = now.duration_since(oldest_sampled_at);
+			if elapsed.as_secs() >= GROWTH_RATE_MIN_SPAN_SECS {
+				let elapsed = elapsed.as_secs_f32();
+
+				// Subtract before converting, since f32 can't represent the block counts of large filesystems exactly.
+				let growth = i128::from(filesystem.used_blocks) - i128::from(oldest_used_blocks);
+				filesystem.growth_rate = Some(growth as f32 / elapsed);
+			}
+		}
+
+		Ok(())
+	}
+}
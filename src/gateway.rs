@@ -1,47 +1,178 @@
 #[derive(Debug)]
 pub(crate) struct Gateways {
-	gateways: std::collections::BTreeMap<String, Option<Gateway>>,
+	inner: std::collections::BTreeMap<String, (crate::opnconfig::Gateway, Option<Gateway>)>,
+	groups: Vec<(crate::opnconfig::GatewayGroup, Vec<MemberStatus>)>,
+	default_routes: Vec<crate::ssh_exec::netstat_rn::DefaultRoute>,
 }
 
 impl Gateways {
-	pub(crate) fn new(gateways: impl IntoIterator<Item = crate::opnconfig::Gateway>) -> Self {
-		let gateways =
+	pub(crate) fn new(
+		gateways: impl IntoIterator<Item = crate::opnconfig::Gateway>,
+		groups: impl IntoIterator<Item = crate::opnconfig::GatewayGroup>,
+	) -> Self {
+		let inner =
 			gateways.into_iter()
-			.map(|crate::opnconfig::Gateway { name }| (name, None))
+			.map(|gateway| (gateway.name.clone(), (gateway, None)))
+			.collect();
+		let groups =
+			groups.into_iter()
+			.map(|group| {
+				let members = vec![MemberStatus::Unusable; group.members.len()];
+				(group, members)
+			})
 			.collect();
 		Gateways {
-			gateways,
+			inner,
+			groups,
+			default_routes: vec![],
 		}
 	}
 
-	pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, Option<Gateway>)> {
-		self.gateways.iter().map(|(name, gateway)| (&**name, *gateway))
+	pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &crate::opnconfig::Gateway, Option<Gateway>)> {
+		self.inner.iter().map(|(name, (config, gateway))| (&**name, config, *gateway))
+	}
+
+	pub(crate) fn groups(&self) -> impl Iterator<Item = (&crate::opnconfig::GatewayGroup, impl Iterator<Item = (&str, u32, MemberStatus)>)> {
+		self.groups.iter().map(|(group, members)| {
+			let members = group.members.iter().zip(members).map(|((name, tier), &status)| (&**name, *tier, status));
+			(group, members)
+		})
+	}
+
+	// Whether the system's current default route for the gateway's address family goes through this gateway.
+	pub(crate) fn is_default_route(&self, config: &crate::opnconfig::Gateway) -> bool {
+		self.default_routes.iter().any(|route| {
+			if route.is_ipv6 != config.is_ipv6 {
+				return false;
+			}
+
+			match config.address {
+				Some(address) => route.gateway == Some(address),
+
+				// Dynamic gateways can only be matched by their interface.
+				None => route.interface == config.interface,
+			}
+		})
 	}
 
 	pub(crate) fn update(&mut self, session: &ssh2::Session) -> Result<(), crate::Error> {
-		for gateway in self.gateways.values_mut() {
+		for (_, gateway) in self.inner.values_mut() {
 			*gateway = None;
 		}
 
 		let gateway_pinger_statistics = crate::ssh_exec::dpinger::get_statistics(session);
 		for gateway_pinger_statistics in gateway_pinger_statistics {
 			let crate::ssh_exec::dpinger::Statistics { name, latency_average, latency_stddev, ping_packet_loss } = gateway_pinger_statistics?;
-			if let Some(gateway) = self.gateways.get_mut(&name) {
+			if let Some((config, gateway)) = self.inner.get_mut(&name) {
+				#[allow(clippy::useless_conversion)] // c_ulong -> u64 is not necessarily identity conversion
+				let packet_loss = u64::from(ping_packet_loss);
+
+				// Same as how OPNsense determines the gateway status from dpinger's statistics.
+				let status =
+					if packet_loss > config.loss_high || latency_average > config.latency_high { Status::Down }
+					else if packet_loss > config.loss_low { Status::Loss }
+					else if latency_average > config.latency_low { Status::Delay }
+					else { Status::Online };
+
 				*gateway = Some(Gateway {
 					latency_average,
 					latency_stddev,
 					ping_packet_loss,
+					status,
 				});
 			}
 		}
 
+		for (group, members) in &mut self.groups {
+			let is_usable = |name: &str|
+				self.inner.get(name).is_some_and(|(_, gateway)| match gateway {
+					Some(Gateway { status, .. }) => !status.triggers(group.trigger),
+
+					// Gateways without monitoring are always considered up.
+					None => true,
+				});
+
+			// Traffic goes to the usable members of the lowest tier that has any. Multiple members in that tier share the load.
+			let active_tier = group.members.iter().filter(|(name, _)| is_usable(name)).map(|&(_, tier)| tier).min();
+
+			for ((name, tier), status) in group.members.iter().zip(members) {
+				*status =
+					if !is_usable(name) { MemberStatus::Unusable }
+					else if Some(*tier) == active_tier { MemberStatus::Active }
+					else { MemberStatus::Standby };
+			}
+		}
+
+		self.default_routes = crate::ssh_exec::netstat_rn::get_default_routes(session)?;
+
 		Ok(())
 	}
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug)]
 pub(crate) struct Gateway {
 	pub(crate) latency_average: std::time::Duration,
 	pub(crate) latency_stddev: std::time::Duration,
 	pub(crate) ping_packet_loss: crate::c_ulong,
+	pub(crate) status: Status,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Status {
+	Online,
+
+	// Latency is above the low threshold
+	Delay,
+
+	// Packet loss is above the low threshold
+	Loss,
+
+	// Latency or packet loss is above the high threshold
+	Down,
+}
+
+impl Status {
+	fn triggers(self, trigger: crate::opnconfig::GatewayGroupTrigger) -> bool {
+		use crate::opnconfig::GatewayGroupTrigger;
+
+		match self {
+			Status::Online => false,
+			Status::Delay => matches!(trigger, GatewayGroupTrigger::DownOrLatency | GatewayGroupTrigger::DownOrLossOrLatency),
+			Status::Loss => matches!(trigger, GatewayGroupTrigger::DownOrLoss | GatewayGroupTrigger::DownOrLossOrLatency),
+			Status::Down => true,
+		}
+	}
+}
+
+impl std::fmt::Display for Status {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Status::Online => f.pad("online"),
+			Status::Delay => f.pad("latency"),
+			Status::Loss => f.pad("packet loss"),
+			Status::Down => f.pad("offline"),
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum MemberStatus {
+	// The member is in the lowest tier that has usable members, so it is carrying traffic.
+	Active,
+
+	// The member is usable but a lower tier is preferred.
+	Standby,
+
+	// The member is failing the group's trigger condition, or the gateway does not exist.
+	Unusable,
+}
+
+impl std::fmt::Display for MemberStatus {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			MemberStatus::Active => f.pad("active"),
+			MemberStatus::Standby => f.pad("standby"),
+			MemberStatus::Unusable => f.pad("unusable"),
+		}
+	}
 }
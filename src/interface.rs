@@ -1,113 +1,390 @@
 #[derive(Debug)]
 pub(crate) struct Interfaces {
-	gateways: std::collections::BTreeMap<String, Interface>,
-	other: std::collections::BTreeMap<String, Interface>,
+	inner: std::collections::BTreeMap<String, Interface>,
+
+	// Parents of VLANs, LAGG members and bridge members from config.xml.
+	// These are overridden by what ifconfig reports.
+	configured_parents: std::collections::BTreeMap<String, String>,
+
+	show_link_local_addresses: bool,
+
+	// Names and depths in display order, as a tree of parents and children.
+	order: Vec<(String, usize)>,
 }
 
 impl Interfaces {
-	pub(crate) fn new(gateways: impl IntoIterator<Item = String>, other: impl IntoIterator<Item = String>) -> Self {
-		let make_pair = |name: String| { let interface = Interface::new(&name); (name, interface) };
-		Interfaces {
-			gateways: gateways.into_iter().map(make_pair).collect(),
-			other: other.into_iter().map(make_pair).collect(),
+	pub(crate) fn new(
+		gateways: impl IntoIterator<Item = String>,
+		other: impl IntoIterator<Item = String>,
+		configured_parents: std::collections::BTreeMap<String, String>,
+		mut address_configs: std::collections::BTreeMap<String, crate::opnconfig::InterfaceAddresses>,
+		show_link_local_addresses: bool,
+	) -> Self {
+		let mut inner: std::collections::BTreeMap<_, _> = Default::default();
+		for (name, is_gateway) in gateways.into_iter().map(|name| (name, true)).chain(other.into_iter().map(|name| (name, false))) {
+			let address_config = address_configs.remove(&name).unwrap_or_default();
+			// Until the interface has been seen, guess from the device names that OPNsense uses for tunnels.
+			let is_tunnel = ["gif", "gre", "ipsec", "ovpn", "tun", "wg"].iter().any(|prefix| name.starts_with(prefix));
+			inner.insert(name, Interface::new(is_gateway, is_tunnel, address_config));
 		}
+
+		let mut result = Interfaces {
+			inner,
+			configured_parents,
+			show_link_local_addresses,
+			order: vec![],
+		};
+		result.update_order();
+		result
+	}
+
+	pub(crate) fn get(&self, name: &str) -> Option<&'_ Interface> {
+		self.inner.get(name)
 	}
 
-	pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = (&'_ str, &'_ mut Interface)> {
-		self.gateways.iter_mut().map(|(name, interface)| (name.as_ref(), interface))
-		.chain(self.other.iter_mut().map(|(name, interface)| (name.as_ref(), interface)))
+	pub(crate) fn iter(&self) -> impl Iterator<Item = (&'_ str, usize, &'_ Interface)> {
+		self.order.iter().filter_map(|(name, depth)| Some((&**name, *depth, self.inner.get(name)?)))
 	}
 
-	pub(crate) fn names(&self) -> impl Iterator<Item = &'_ str> {
-		self.gateways.keys().map(AsRef::as_ref)
-		.chain(self.other.keys().map(AsRef::as_ref))
+	fn parent(&self, name: &str) -> Option<&str> {
+		let interface = self.inner.get(name)?;
+
+		let parent =
+			interface.ifconfig.vlan_parent.as_deref()
+			.or_else(|| self.inner.iter().find_map(|(parent_name, parent)|
+				parent.ifconfig.bridge_members.iter().any(|member| member == name).then_some(&**parent_name)))
+			.or_else(|| self.configured_parents.get(name).map(AsRef::as_ref))?;
+
+		// Parents that aren't shown, like the unassigned NIC of a VLAN, are ignored.
+		(parent != name && self.inner.contains_key(parent)).then_some(parent)
+	}
+
+	fn update_order(&mut self) {
+		fn visit(
+			children: &std::collections::BTreeMap<&str, Vec<&str>>,
+			name: &str,
+			depth: usize,
+			order: &mut Vec<(String, usize)>,
+		) {
+			if order.iter().any(|(visited, _)| visited == name) {
+				return;
+			}
+
+			order.push((name.to_owned(), depth));
+
+			for &child in children.get(name).into_iter().flatten() {
+				visit(children, child, depth + 1, order);
+			}
+		}
+
+		let mut children: std::collections::BTreeMap<_, Vec<_>> = Default::default();
+		let mut roots = vec![];
+		for name in self.inner.keys() {
+			match self.parent(name) {
+				Some(parent) => children.entry(parent).or_default().push(&**name),
+				None => roots.push(&**name),
+			}
+		}
+
+		// Gateway interfaces first, then other interfaces, then tunnels.
+		roots.sort_by_key(|&name| {
+			let interface = &self.inner[name];
+			(interface.is_tunnel, !interface.is_gateway, name)
+		});
+
+		let mut order = vec![];
+		for root in roots {
+			visit(&children, root, 0, &mut order);
+		}
+
+		// Interfaces in a parent cycle aren't reachable from any root.
+		for name in self.inner.keys() {
+			visit(&children, name, 0, &mut order);
+		}
+
+		self.order = order;
 	}
 
 	pub(crate) fn update(&mut self, session: &ssh2::Session) -> Result<(), crate::Error> {
-		for (_, interface) in self.iter_mut() {
-			interface.addresses.clear();
+		let mut ifconfigs = crate::ssh_exec::ifconfig::run(session)?;
+
+		for (name, interface) in &mut self.inner {
+			interface.counters_previous = interface.counters.take();
 
-			interface.received_bytes_previous = interface.received_bytes;
-			interface.received_bytes = 0;
+			// Interfaces like PPPoE, OpenVPN and WireGuard are destroyed and recreated when their connection goes down and up.
+			let ifconfig = ifconfigs.remove(name);
+			interface.is_present = ifconfig.is_some();
+			interface.ifconfig = ifconfig.unwrap_or_default();
 
-			interface.sent_bytes_previous = interface.sent_bytes;
-			interface.sent_bytes = 0;
+			// Keep the last known classification while the interface is missing, so that its row doesn't move around when it flaps.
+			if interface.is_present {
+				interface.is_tunnel = interface.ifconfig.groups.iter().any(|group| matches!(&**group, "gif" | "gre" | "ipsec" | "openvpn" | "tun" | "wg"));
+			}
+
+			interface.addresses.clear();
+			for address in &interface.ifconfig.addresses {
+				let role = address_role(address, &interface.address_config);
+				if matches!(role, Some(AddressRole::LinkLocal)) && !self.show_link_local_addresses {
+					continue;
+				}
 
-			interface.error = interface.ifconfig_exec.run(session)?;
+				interface.addresses.push(Address {
+					ip: address.ip,
+					prefix_len: address.prefix_len,
+					role,
+					flags:
+						address.flags.iter()
+						.filter(|flag| matches!(&***flag, "deprecated" | "detached" | "duplicated" | "tentative"))
+						.cloned()
+						.collect(),
+				});
+			}
+			interface.addresses.sort_by_key(|address| InterfaceAddressOrdered(address.ip));
 		}
 
 		let interface_statistics = crate::ssh_exec::netstat_bin::get_interfaces(session)?;
 
 		for interface_statistics in interface_statistics {
 			let interface_name = interface_statistics.name;
-			let interface =
-				self.gateways.get_mut(&interface_name)
-				.or_else(|| self.other.get_mut(&interface_name));
-
-			if let Some(interface) = interface {
+			if let Some(interface) = self.inner.get_mut(&interface_name) {
+				// The link-level entry has the counters for the whole interface. The addresses come from ifconfig.
 				if interface_statistics.network.starts_with("<Link#") {
-					interface.received_bytes += interface_statistics.received_bytes;
-					interface.sent_bytes += interface_statistics.sent_bytes;
-				}
-				else if !interface_statistics.address.starts_with("fe80:") {
-					let address = interface_statistics.address.parse()?;
-					let _ = interface.addresses.insert(InterfaceAddressOrdered(address));
+					let counters = interface.counters.get_or_insert_with(Default::default);
+					counters.received_bytes += interface_statistics.received_bytes;
+					counters.sent_bytes += interface_statistics.sent_bytes;
+					counters.received_packets += interface_statistics.received_packets;
+					counters.sent_packets += interface_statistics.sent_packets;
+					counters.errors += interface_statistics.received_errors + interface_statistics.sent_errors;
+					counters.drops += interface_statistics.received_drops;
 				}
 			}
 		}
 
+		self.update_order();
+
 		Ok(())
 	}
 }
 
 #[derive(Debug)]
 pub(crate) struct Interface {
-	ifconfig_exec: crate::ssh_exec::ifconfig::Exec,
+	is_gateway: bool,
 
-	pub(crate) error: Option<String>,
+	is_present: bool,
+	is_tunnel: bool,
+	pub(crate) ifconfig: crate::ssh_exec::ifconfig::Ifconfig,
 
-	addresses: std::collections::BTreeSet<InterfaceAddressOrdered>,
+	address_config: crate::opnconfig::InterfaceAddresses,
+	addresses: Vec<Address>,
 
-	received_bytes_previous: u64,
-	received_bytes: u64,
+	// None if the interface did not exist at the time of the sample.
+	counters_previous: Option<Counters>,
+	counters: Option<Counters>,
+}
+
+type Counter = fn(&Counters) -> u64;
 
-	sent_bytes_previous: u64,
+#[derive(Clone, Copy, Debug, Default)]
+struct Counters {
+	received_bytes: u64,
 	sent_bytes: u64,
+	received_packets: u64,
+	sent_packets: u64,
+	errors: u64,
+	drops: u64,
 }
 
-impl Interface {
-	fn new(name: &str) -> Self {
-		let ifconfig_exec = crate::ssh_exec::ifconfig::Exec::new(name);
+// Per second
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PacketRates {
+	pub(crate) received_packets: f32,
+	pub(crate) sent_packets: f32,
+	pub(crate) errors: f32,
+	pub(crate) drops: f32,
+}
 
+impl Interface {
+	fn new(is_gateway: bool, is_tunnel: bool, address_config: crate::opnconfig::InterfaceAddresses) -> Self {
 		Interface {
-			ifconfig_exec,
+			is_gateway,
 
-			error: None,
+			is_present: false,
+			is_tunnel,
+			ifconfig: Default::default(),
 
-			addresses: Default::default(),
+			address_config,
+			addresses: vec![],
 
-			received_bytes_previous: 0,
-			received_bytes: 0,
-
-			sent_bytes_previous: 0,
-			sent_bytes: 0,
+			counters_previous: None,
+			counters: None,
 		}
 	}
 
-	pub(crate) fn addresses(&self) -> impl Iterator<Item = std::net::IpAddr> + '_ {
-		self.addresses.iter().map(|address| address.0)
+	pub(crate) fn addresses(&self) -> impl Iterator<Item = &'_ Address> {
+		self.addresses.iter()
 	}
 
 	pub(crate) fn speed(&self, time_since_previous: std::time::Duration) -> Option<(f32, f32)> {
-		if self.received_bytes_previous > 0 && self.sent_bytes_previous > 0 {
-			let interface_received_speed = (self.received_bytes.saturating_sub(self.received_bytes_previous)) as f32 / time_since_previous.as_secs_f32() * 8.;
-			let interface_sent_speed = (self.sent_bytes.saturating_sub(self.sent_bytes_previous)) as f32 / time_since_previous.as_secs_f32() * 8.;
-			Some((interface_received_speed, interface_sent_speed))
+		let interface_received_speed = self.rate(|counters| counters.received_bytes, time_since_previous)? * 8.;
+		let interface_sent_speed = self.rate(|counters| counters.sent_bytes, time_since_previous)? * 8.;
+		Some((interface_received_speed, interface_sent_speed))
+	}
+
+	// Bytes received and sent since the previous update
+	pub(crate) fn transferred_bytes(&self) -> Option<(u64, u64)> {
+		Some((
+			self.increase(|counters| counters.received_bytes)?,
+			self.increase(|counters| counters.sent_bytes)?,
+		))
+	}
+
+	pub(crate) fn status(&self) -> crate::ssh_exec::ifconfig::Status {
+		if self.is_present {
+			self.ifconfig.status()
 		}
 		else {
-			None
+			crate::ssh_exec::ifconfig::Status::NotPresent
 		}
 	}
+
+	// LAGG ports that are not active, or for LACP, also not collecting and distributing, are unhealthy.
+	pub(crate) fn is_lagg_port_healthy(&self, lagg_port: &crate::ssh_exec::ifconfig::LaggPort) -> bool {
+		let has_flag = |flag: &str| lagg_port.flags.iter().any(|lagg_port_flag| lagg_port_flag == flag);
+		has_flag("ACTIVE") && (self.ifconfig.lagg_proto.as_deref() != Some("lacp") || (has_flag("COLLECTING") && has_flag("DISTRIBUTING")))
+	}
+
+	// Percentage of the link speed used by the busier direction, since links are full-duplex.
+	pub(crate) fn utilization(&self, time_since_previous: std::time::Duration) -> Option<f32> {
+		let link_speed = self.ifconfig.media.as_ref()?.speed?;
+		let (received_speed, sent_speed) = self.speed(time_since_previous)?;
+		Some(received_speed.max(sent_speed) / link_speed * 100.)
+	}
+
+	pub(crate) fn packet_rates(&self, time_since_previous: std::time::Duration) -> Option<PacketRates> {
+		Some(PacketRates {
+			received_packets: self.rate(|counters| counters.received_packets, time_since_previous)?,
+			sent_packets: self.rate(|counters| counters.sent_packets, time_since_previous)?,
+			errors: self.rate(|counters| counters.errors, time_since_previous)?,
+			drops: self.rate(|counters| counters.drops, time_since_previous)?,
+		})
+	}
+
+	fn rate(&self, counter: Counter, time_since_previous: std::time::Duration) -> Option<f32> {
+		Some(self.increase(counter)? as f32 / time_since_previous.as_secs_f32())
+	}
+
+	// The increase in the counter between the previous and current samples.
+	// Only available if the interface existed at the time of both samples.
+	fn increase(&self, counter: Counter) -> Option<u64> {
+		let previous = counter(self.counters_previous.as_ref()?);
+		let current = counter(self.counters.as_ref()?);
+
+		if let Some(increase) = current.checked_sub(previous) {
+			return Some(increase);
+		}
+
+		// Some drivers only have 32-bit counters. The counter is only assumed to have wrapped around if the previous value
+		// was within 2^28 of the 32-bit limit and the current value is below 2^28. A drop from anywhere else is a reset.
+		//
+		// This applies to the byte, packet, error and drop counters alike. It misses wraps of counters that increase by more than
+		// 2^28 between samples (about 256 MiB for the byte counters), but those are treated as resets instead of being counted
+		// as a huge increase.
+		if let Ok(previous) = u32::try_from(previous) {
+			const WRAP_MARGIN: u64 = 1 << 28;
+
+			let distance_to_limit = u64::from(u32::MAX - previous);
+			if distance_to_limit < WRAP_MARGIN && current < WRAP_MARGIN {
+				return Some(distance_to_limit + current + 1);
+			}
+		}
+
+		// Otherwise the counters were reset, such as by the interface being destroyed and recreated between samples,
+		// so the current value is everything since the reset.
+		Some(current)
+	}
+}
+
+#[derive(Debug)]
+pub(crate) struct Address {
+	ip: std::net::IpAddr,
+	prefix_len: u8,
+	role: Option<AddressRole>,
+	flags: Vec<String>,
+}
+
+impl std::fmt::Display for Address {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}/{}", self.ip, self.prefix_len)?;
+
+		let mut tags = self.role.map(AddressRole::name).into_iter().chain(self.flags.iter().map(AsRef::as_ref));
+		if let Some(tag) = tags.next() {
+			write!(f, " ({tag}")?;
+			for tag in tags {
+				write!(f, ", {tag}")?;
+			}
+			f.write_str(")")?;
+		}
+
+		Ok(())
+	}
+}
+
+#[derive(Clone, Copy, Debug)]
+enum AddressRole {
+	Carp,
+	Dhcp,
+	LinkLocal,
+	Slaac,
+	Static,
+	Vip,
+}
+
+impl AddressRole {
+	fn name(self) -> &'static str {
+		match self {
+			AddressRole::Carp => "CARP",
+			AddressRole::Dhcp => "DHCP",
+			AddressRole::LinkLocal => "link-local",
+			AddressRole::Slaac => "SLAAC",
+			AddressRole::Static => "static",
+			AddressRole::Vip => "VIP",
+		}
+	}
+}
+
+fn address_role(address: &crate::ssh_exec::ifconfig::Address, address_config: &crate::opnconfig::InterfaceAddresses) -> Option<AddressRole> {
+	let is_link_local = match address.ip {
+		std::net::IpAddr::V4(address) => address.is_link_local(),
+		std::net::IpAddr::V6(address) => address.is_unicast_link_local(),
+	};
+
+	if address.vhid.is_some() {
+		return Some(AddressRole::Carp);
+	}
+
+	if address_config.virtual_ips.contains(&address.ip) {
+		return Some(AddressRole::Vip);
+	}
+
+	if is_link_local {
+		return Some(AddressRole::LinkLocal);
+	}
+
+	if address.flags.iter().any(|flag| flag == "autoconf") {
+		return Some(AddressRole::Slaac);
+	}
+
+	let configured = match address.ip {
+		std::net::IpAddr::V4(_) => address_config.ipaddr.as_deref(),
+		std::net::IpAddr::V6(_) => address_config.ipaddrv6.as_deref(),
+	};
+	match configured? {
+		configured if configured.parse() == Ok(address.ip) => Some(AddressRole::Static),
+		// track6 addresses come from a prefix delegated by DHCPv6 on another interface.
+		"dhcp" | "dhcp6" | "track6" => Some(AddressRole::Dhcp),
+		"slaac" => Some(AddressRole::Slaac),
+		_ => None,
+	}
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
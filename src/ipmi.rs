@@ -0,0 +1,70 @@
+#[derive(Debug)]
+pub(crate) struct IpmiSensors {
+	filter: crate::config::Filter,
+	refresh_interval: std::time::Duration,
+	last_refresh: Option<std::time::Instant>,
+
+	sensors: Vec<crate::ssh_exec::ipmitool_sensor::Sensor>,
+}
+
+impl IpmiSensors {
+	pub(crate) fn new(crate::config::Ipmi { sensors: filter, refresh_interval }: crate::config::Ipmi, session: &ssh2::Session) -> Result<Self, crate::Error> {
+		let mut result = IpmiSensors {
+			filter,
+			refresh_interval: std::time::Duration::from_secs(refresh_interval),
+			last_refresh: None,
+
+			sensors: vec![],
+		};
+
+		// Refresh once up-front so that the list of sensors is known for the layout.
+		result.update(session)?;
+
+		Ok(result)
+	}
+
+	pub(crate) fn iter(&self) -> impl Iterator<Item = &'_ crate::ssh_exec::ipmitool_sensor::Sensor> {
+		self.sensors.iter()
+	}
+
+	pub(crate) fn update(&mut self, session: &ssh2::Session) -> Result<(), crate::Error> {
+		// ipmitool can take several seconds to query the BMC, so it is run less often than everything else.
+		let now = std::time::Instant::now();
+		if self.last_refresh.is_some_and(|last_refresh| now.duration_since(last_refresh) < self.refresh_interval) {
+			return Ok(());
+		}
+		self.last_refresh = Some(now);
+
+		let sensors: Result<Vec<_>, crate::Error> =
+			crate::ssh_exec::ipmitool_sensor::run(session)
+			.filter(|sensor| sensor.as_ref().map_or(true, |sensor| self.filter.matches(&sensor.name)))
+			.collect();
+		let mut sensors = sensors?;
+
+		// `ipmitool sensor` only shows the raw state bits of discrete sensors like power supplies,
+		// so their status and decoded states come from `ipmitool sdr` instead.
+		if sensors.iter().any(|sensor| sensor.unit == "discrete") {
+			for sdr in crate::ssh_exec::ipmitool_sdr_elist::run(session) {
+				let crate::ssh_exec::ipmitool_sdr_elist::Sdr { name, status, description } = sdr?;
+				if let Some(sensor) = sensors.iter_mut().find(|sensor| sensor.unit == "discrete" && sensor.name == name) {
+					sensor.status = status;
+					sensor.description = (!description.is_empty()).then_some(description);
+				}
+			}
+		}
+
+		// Keep the set of sensors from the first refresh so that the number of output lines stays constant.
+		if self.sensors.is_empty() {
+			self.sensors = sensors;
+		}
+		else {
+			for sensor in sensors {
+				if let Some(existing) = self.sensors.iter_mut().find(|existing| existing.name == sensor.name) {
+					*existing = sensor;
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
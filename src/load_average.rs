@@ -0,0 +1,26 @@
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct LoadAverage(pub(crate) [f32; 3]);
+
+impl crate::Parse for LoadAverage {
+	fn parse<R>(reader: &mut R) -> std::io::Result<Self> where R: std::io::Read {
+		// struct loadavg {
+		//     fixpt_t ldavg[3];
+		//     long fscale;
+		// };
+
+		let mut ldavg: [crate::fixpt_t; 3] = Default::default();
+		for ldavg in &mut ldavg {
+			*ldavg = crate::Parse::parse(reader)?;
+		}
+
+		// fscale is aligned to the size of long, so there may be padding after ldavg
+		let padding = (std::mem::size_of::<crate::c_long>() - std::mem::size_of_val(&ldavg) % std::mem::size_of::<crate::c_long>()) % std::mem::size_of::<crate::c_long>();
+		let mut buf = [0_u8; std::mem::size_of::<crate::c_long>()];
+		let () = std::io::Read::read_exact(reader, &mut buf[..padding])?;
+
+		let fscale: crate::c_long = crate::Parse::parse(reader)?;
+		let fscale = fscale as f32;
+
+		Ok(LoadAverage(ldavg.map(|ldavg| ldavg as f32 / fscale)))
+	}
+}
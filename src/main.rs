@@ -12,14 +12,24 @@ mod ssh_exec;
 
 mod boot_time;
 mod cpu;
+mod cpu_frequency_sysctl;
+mod cpu_info;
 mod disk;
+mod filesystem;
 mod firewall_logs;
 mod gateway;
 mod interface;
+mod ipmi;
+mod load_average;
+mod mbuf;
 mod memory;
+mod pf_info;
 mod service;
+mod state_holders;
 mod temperature_sysctl;
+mod traffic;
 mod version_info;
+mod zfs_pool;
 
 use std::io::Write;
 
@@ -33,10 +43,19 @@ use std::io::Write;
 const ENDIANNESS: Endianness = Endianness::Little;
 // const ENDIANNESS: Endianness = Endianness::Big;
 
+// The `int` type
+#[allow(non_camel_case_types)]
+type c_int = i32;
+
 // The `unsigned int` type
 #[allow(non_camel_case_types)]
 type c_uint = u32;
 
+// The `long int` type
+#[allow(non_camel_case_types)]
+type c_long = i64;
+// type c_long = i32;
+
 // The `unsigned long int` type
 #[allow(non_camel_case_types)]
 type c_ulong = u64;
@@ -46,6 +65,10 @@ type c_ulong = u64;
 #[allow(non_camel_case_types)]
 type time_t = c_ulong;
 
+// The `fixpt_t` type
+#[allow(non_camel_case_types)]
+type fixpt_t = u32;
+
 //
 // ----------------------------------------------------------------------------
 
@@ -79,51 +102,121 @@ fn main() -> Result<(), Error> {
 
 	let mut cpu = cpu::Cpu::new();
 
-	let (boot_time, mut memory) = ssh_exec::batched_sysctls_1::run(&session)?;
+	let (boot_time, memory, cpu_info::CpuInfo { num_cpus, model: cpu_model }) = ssh_exec::batched_sysctls_1::run(&session)?;
+
+	let mut load_average = Default::default();
 
-	let mut disks = disk::Disk::get_all(&session)?;
+	let mut disks = disk::Disk::get_all(&config.disks, &session)?;
 	let max_disk_name_len = disks.iter().map(|disk::Disk { name, .. }| name.len()).max().unwrap_or_default();
-	let max_disk_serial_number_len = disks.iter().map(|disk::Disk { serial_number, .. }| serial_number.len()).max().unwrap_or_default();
+	let max_disk_serial_number_len = disks.iter().map(|disk::Disk { serial_number, .. }| serial_number.as_deref().map_or(3, str::len)).max().unwrap_or_default();
+
+	let sysctl_names: Vec<_> = ssh_exec::sysctl_aN::run(&session).collect::<Result<_, _>>()?;
+
+	let mut memory = memory.with_arc(&sysctl_names);
 
-	let mut temperature_sysctls = temperature_sysctl::TemperatureSysctl::get_all(&session)?;
+	let mut temperature_sysctls = temperature_sysctl::TemperatureSysctl::get_all(&sysctl_names, &config.temperatures.sysctls, &session)?;
 
-	let batched_sysctls_exec = ssh_exec::batched_sysctls_2::Exec::new(&temperature_sysctls[..]);
+	let mut cpu_frequency_sysctls = cpu_frequency_sysctl::CpuFrequencySysctl::get_all(&sysctl_names);
+
+	let batched_sysctls_exec = ssh_exec::batched_sysctls_2::Exec::new(&memory, &temperature_sysctls[..], &cpu_frequency_sysctls[..]);
+
+	let mut ipmi_sensors = config.ipmi.map(|ipmi| ipmi::IpmiSensors::new(ipmi, &session)).transpose()?;
 
 	let max_thermal_sensor_name_len =
 		temperature_sysctls.iter().map(|temperature_sysctl::TemperatureSysctl { name, .. }| name)
-		.chain(disks.iter().map(|disk::Disk { name, .. }| name))
-		.map(String::len).max().unwrap_or_default();
+		.chain(disks.iter().filter(|disk| disk.has_smart()).map(|disk::Disk { name, .. }| name))
+		.chain(ipmi_sensors.iter().flat_map(ipmi::IpmiSensors::iter).map(|ssh_exec::ipmitool_sensor::Sensor { name, .. }| name))
+		.map(|name| config.temperatures.display_name(name).len()).max().unwrap_or_default();
+
+	let interface_display_names: std::collections::BTreeMap<_, _> =
+		opnconfig.interface_descriptions.iter()
+		.map(|(r#if, description)| (&**r#if, config.interfaces.names.display_name(r#if, description)))
+		.collect();
+
+	let disabled_interfaces: std::collections::BTreeSet<_> =
+		opnconfig.interface_descriptions.iter()
+		.filter(|(_, description)| !description.enabled)
+		.map(|(r#if, _)| &**r#if)
+		.collect();
+	let show_interface = |name: &String| match config.interfaces.disabled {
+		config::DisabledInterfaces::Dim => true,
+		config::DisabledInterfaces::Hide => !disabled_interfaces.contains(&**name),
+	};
+
+	let mut interfaces = interface::Interfaces::new(
+		opnconfig.gateway_interfaces.iter().filter(|name| show_interface(name)).cloned(),
+		opnconfig.other_interfaces.into_iter().filter(show_interface),
+		opnconfig.interface_parents,
+		opnconfig.interface_addresses,
+		config.interfaces.link_local_addresses,
+	);
 
-	let mut interfaces = interface::Interfaces::new(opnconfig.gateway_interfaces.iter().cloned(), opnconfig.other_interfaces);
-	let max_interface_name_len = interfaces.names().map(str::len).max().unwrap_or_default();
+	let mut traffic = config.traffic.map(|traffic| traffic::Traffic::new(traffic, &interfaces, &session)).transpose()?;
 
-	let mut gateways = gateway::Gateways::new(opnconfig.gateways);
-	let max_gateway_name_len = gateways.iter().map(|(name, _)| name.len()).max().unwrap_or_default();
+	let mut gateways = gateway::Gateways::new(opnconfig.gateways, opnconfig.gateway_groups);
+	let max_gateway_name_len = gateways.iter().map(|(name, _, _)| name.len()).max().unwrap_or_default();
+	let max_gateway_group_name_len = gateways.groups().map(|(opnconfig::GatewayGroup { name, .. }, _)| name.len()).max().unwrap_or_default();
+	let max_gateway_monitor_len = gateways.iter().map(|(_, opnconfig::Gateway { monitor, .. }, _)| monitor.as_deref().map_or(0, str::len)).max().unwrap_or_default();
 
 	let mut services = service::Service::get_all(config.services)?;
 	let max_service_name_len = services.iter().map(|service::Service { name, .. }| name.len()).max().unwrap_or_default();
 
-	let max_firewall_log_interface_name_len = opnconfig.gateway_interfaces.iter().map(String::len).max().unwrap_or_default();
+	let max_firewall_log_interface_name_len = opnconfig.gateway_interfaces.iter().map(|name| interface_display_name(&interface_display_names, name).len()).max().unwrap_or_default();
 	let mut firewall_logs = firewall_logs::Logs::new(opnconfig.gateway_interfaces);
 
 
+	let mut pf_info = pf_info::PfInfo::new();
+
+	let mut mbufs = mbuf::MBufs::new();
+
+	let mut filesystems = filesystem::Filesystems::new(config.filesystems);
+
+	// Only show the ZFS pools section if the router has any pools to begin with.
+	let mut zfs_pools = zfs_pool::ZfsPool::get_all(&session)?;
+	let show_zfs_pools = !zfs_pools.is_empty();
+
+	let mut state_holders = config.state_holders.map(state_holders::StateHolders::new);
+
+
 	let mut previous = std::time::SystemTime::now();
 
 
 	loop {
-		batched_sysctls_exec.run(&mut cpu, &mut memory, &mut temperature_sysctls[..], &session)?;
+		batched_sysctls_exec.run(&mut cpu, &mut memory, &mut load_average, &mut temperature_sysctls[..], &mut cpu_frequency_sysctls[..], &session)?;
+
+		let swap = ssh_exec::swapinfo_k::get_swap(&session)?;
+
+		pf_info.update(&session)?;
 
-		let states_used = ssh_exec::pfctl_s_info::get_states_used(&session)?;
-		let ssh_exec::netstat_m::MBufStatistics { cluster_total: mbufs_used, cluster_max: mbufs_max } = ssh_exec::netstat_m::get_mbuf_statistics(&session)?;
+		if let Some(state_holders) = &mut state_holders {
+			state_holders.update(&session)?;
+		}
+
+		let states_max = ssh_exec::pfctl_s_memory::get_states_limit(&session)?;
+		mbufs.update(&session)?;
 
-		let filesystems = ssh_exec::df::get_filesystems(&session)?;
+		filesystems.update(&session)?;
+
+		if show_zfs_pools {
+			zfs_pools = zfs_pool::ZfsPool::get_all(&session)?;
+		}
 
 		for disk in &mut disks[..] {
 			disk.update(&session)?;
 		}
 
+		disk::Disk::update_io_all(&mut disks, &session)?;
+
+		if let Some(ipmi_sensors) = &mut ipmi_sensors {
+			ipmi_sensors.update(&session)?;
+		}
+
 		interfaces.update(&session)?;
 
+		if let Some(traffic) = &mut traffic {
+			traffic.update(&interfaces, &session)?;
+		}
+
 		gateways.update(&session)?;
 
 		for service in &mut services[..] {
@@ -143,14 +236,14 @@ fn main() -> Result<(), Error> {
 		//
 		// We don't clear_screen every time because it's slow in some terminal emulators, like tmux, and causes flickering.
 		// sync makes it better on terminal emulators that support it, but tmux doesn't. So we only do clear_screen when the screen size changes.
-		// At other times, we leave the first two lines of version info intact (since they're constant),
-		// reset the cursor to just after the version info, and clear_line each line before we write a new one.
+		// At other times, we leave the first three lines of version and CPU info intact (since they're constant),
+		// reset the cursor to just after them, and clear_line each line before we write a new one.
 		//
 		// The disadvantage of this method is that it relies on the number of output lines being constant.
 		// There are two situations where this assumption doesn't hold:
 		//
 		// - The number of mounted filesystems changes. This is only an issue if you mount or unmount a filesystem dynamically.
-		// - The number of IPs assigned to any interfaces changes. This should only happen if you change your interface settings.
+		// - The number of IPs assigned to any interfaces, or the number of LAGG ports, changes. This should only happen if you change your interface settings.
 		//
 		// In either case, triggering a resize of the dashboard will fix the output.
 
@@ -160,13 +253,14 @@ fn main() -> Result<(), Error> {
 
 		let terminal_width: usize = terminal::Terminal::width(&stdout)?;
 		if previous_terminal_width == Some(terminal_width) {
-			terminfo.move_cursor(2, 0, &mut output)?;
+			terminfo.move_cursor(3, 0, &mut output)?;
 		}
 		else {
 			output.extend_from_slice(terminfo.clear_screen());
 			output.extend_from_slice(terminfo.clear_scrollback());
 			writeln!(output, "Version       : {product_name} {product_version}-{product_arch}")?;
 			writeln!(output, "                {os_base_version}")?;
+			writeln!(output, "CPU           : {cpu_model} ({num_cpus} cores)")?;
 		}
 
 
@@ -185,6 +279,17 @@ fn main() -> Result<(), Error> {
 		}
 
 
+		{
+			output.push(b'\n');
+			output.extend_from_slice(terminfo.clear_line());
+			output.extend_from_slice(b"Load average  :");
+			for load_average in load_average.0 {
+				let (_, load_average_color) = usage(load_average, num_cpus as f32);
+				write!(output, " \x1B[{load_average_color}m{load_average:5.2}\x1B[0m")?;
+			}
+		}
+
+
 		{
 			output.push(b'\n');
 			output.extend_from_slice(terminfo.clear_line());
@@ -196,20 +301,64 @@ fn main() -> Result<(), Error> {
 			else {
 				output.extend_from_slice(b"    ? %");
 			}
+
+			if !cpu_frequency_sysctls.is_empty() {
+				let cpu_frequency =
+					cpu_frequency_sysctls.iter().map(|cpu_frequency_sysctl::CpuFrequencySysctl { value, .. }| *value as f32).sum::<f32>() /
+					cpu_frequency_sysctls.len() as f32;
+				write!(output, " at {cpu_frequency:4.0} MHz")?;
+			}
+		}
+
+
+		{
+			let (memory_usage_percent, memory_usage_color) = usage(memory.used() as f32, memory.total() as f32);
+			output.push(b'\n');
+			output.extend_from_slice(terminfo.clear_line());
+			write!(output, "Memory usage  : \x1B[{memory_usage_color}m{memory_usage_percent:5.1} % of {} MiB\x1B[0m", memory.total() / 1_048_576)?;
+
+			output.push(b'\n');
+			output.extend_from_slice(terminfo.clear_line());
+			write!(
+				output,
+				"                {} MiB active, {} MiB inactive, {} MiB laundry, {} MiB wired, {} MiB free",
+				memory.pages_to_bytes(memory.active_pages) / 1_048_576,
+				memory.pages_to_bytes(memory.inactive_pages) / 1_048_576,
+				memory.pages_to_bytes(memory.laundry_pages) / 1_048_576,
+				memory.pages_to_bytes(memory.wired_pages) / 1_048_576,
+				memory.pages_to_bytes(memory.free_pages) / 1_048_576,
+			)?;
+
+			if let Some(memory::Arc { size, target_min, target_max }) = memory.arc {
+				output.push(b'\n');
+				output.extend_from_slice(terminfo.clear_line());
+				write!(
+					output,
+					"                {} MiB ARC ({} MiB - {} MiB target)",
+					size / 1_048_576,
+					target_min / 1_048_576,
+					target_max / 1_048_576,
+				)?;
+			}
 		}
 
 
 		{
-			let (memory_usage_percent, memory_usage_color) = usage(memory.used_pages as f32, memory.num_pages as f32);
 			output.push(b'\n');
 			output.extend_from_slice(terminfo.clear_line());
-			write!(output, "Memory usage  : \x1B[{memory_usage_color}m{memory_usage_percent:5.1} % of {} MiB\x1B[0m", memory.physical / 1_048_576)?;
+			if swap.total > 0 {
+				let (swap_usage_percent, swap_usage_color) = usage(swap.used as f32, swap.total as f32);
+				write!(output, "Swap usage    : \x1B[{swap_usage_color}m{swap_usage_percent:5.1} % of {} MiB\x1B[0m", swap.total / 1024)?;
+			}
+			else {
+				output.extend_from_slice(b"Swap usage    : none");
+			}
 		}
 
 
 		{
-			let states_max = (memory.physical / 10_485_760) * 1000;
-			let (states_usage_percent, states_usage_color) = usage(states_used, states_max as f32);
+			let states_used = pf_info.states_used;
+			let (states_usage_percent, states_usage_color) = usage(states_used as f32, states_max);
 			output.push(b'\n');
 			output.extend_from_slice(terminfo.clear_line());
 			write!(output, "States table  : \x1B[{states_usage_color}m{states_usage_percent:5.1} % ({states_used:7} / {states_max:7})\x1B[0m")?;
@@ -217,10 +366,166 @@ fn main() -> Result<(), Error> {
 
 
 		{
-			let (mbufs_usage_percent, mbufs_usage_color) = usage(mbufs_used as f32, mbufs_max as f32);
+			let pf_status_color = get_color_for_up_down(pf_info.is_enabled());
 			output.push(b'\n');
 			output.extend_from_slice(terminfo.clear_line());
-			write!(output, "MBUF usage    : \x1B[{mbufs_usage_color}m{mbufs_usage_percent:5.1} % ({mbufs_used:7} / {mbufs_max:7})\x1B[0m")?;
+			write!(output, "pf status     : \x1B[{pf_status_color}m{}\x1B[0m (debug {})", pf_info.status, pf_info.debug)?;
+		}
+
+
+		{
+			output.push(b'\n');
+			output.extend_from_slice(terminfo.clear_line());
+			output.extend_from_slice(b"pf state ops  :");
+
+			let state_ops: [(&str, pf_info::Counter); 3] = [
+				("searches", |counters| counters.searches),
+				("inserts", |counters| counters.inserts),
+				("removals", |counters| counters.removals),
+			];
+			for (name, counter) in state_ops {
+				match pf_info.rate(counter, time_since_previous) {
+					Some(rate) => write!(output, " {rate:9.1}/s {name:14}")?,
+					None => write!(output, "         ?/s {name:14}")?,
+				}
+			}
+		}
+
+
+		{
+			output.push(b'\n');
+			output.extend_from_slice(terminfo.clear_line());
+			output.extend_from_slice(b"pf counters   :");
+
+			// match is the only counter that increments during normal operation. The others indicate dropped packets.
+			let counters: [(&str, pf_info::Counter); 8] = [
+				("match", |counters| counters.r#match),
+				("bad-offset", |counters| counters.bad_offset),
+				("fragment", |counters| counters.fragment),
+				("short", |counters| counters.short),
+				("normalize", |counters| counters.normalize),
+				("memory", |counters| counters.memory),
+				("state-mismatch", |counters| counters.state_mismatch),
+				("state-limit", |counters| counters.state_limit),
+			];
+			for (i, (name, counter)) in counters.into_iter().enumerate() {
+				if i > 0 && i % 4 == 0 {
+					output.push(b'\n');
+					output.extend_from_slice(terminfo.clear_line());
+					output.extend_from_slice(b"               ");
+				}
+
+				match pf_info.rate(counter, time_since_previous) {
+					Some(rate) if i == 0 => write!(output, " {rate:9.1}/s {name:14}")?,
+					Some(rate) => {
+						let counter_color = get_color_for_up_down(rate == 0.);
+						write!(output, " \x1B[{counter_color}m{rate:9.1}/s {name:14}\x1B[0m")?;
+					},
+					None => write!(output, "         ?/s {name:14}")?,
+				}
+			}
+		}
+
+
+		if let Some(state_holders) = &state_holders {
+			output.push(b'\n');
+			output.extend_from_slice(terminfo.clear_line());
+			output.extend_from_slice(b"State holders :");
+
+			let columns = [
+				&state_holders.sources[..],
+				&state_holders.destinations[..],
+				&state_holders.destination_ports[..],
+				&state_holders.interfaces[..],
+			];
+			let headers = ["source", "destination", "port", "interface"];
+			let mut max_key_lens = headers.map(str::len);
+			for (column, max_key_len) in columns.into_iter().zip(&mut max_key_lens) {
+				*max_key_len = column.iter().map(|(key, _)| key.len()).fold(*max_key_len, std::cmp::max);
+			}
+
+			for (header, max_key_len) in headers.into_iter().zip(max_key_lens) {
+				write!(output, " {:>7} {header:max_key_len$}", "states")?;
+			}
+
+			// Always print `count` rows so that the number of output lines is constant.
+			for i in 0..state_holders.count() {
+				output.push(b'\n');
+				output.extend_from_slice(terminfo.clear_line());
+				output.extend_from_slice(b"               ");
+
+				for (column, max_key_len) in columns.into_iter().zip(max_key_lens) {
+					match column.get(i) {
+						Some((key, count)) => write!(output, " {count:7} {key:max_key_len$}")?,
+						None => write!(output, " {:7} {:max_key_len$}", "", "")?,
+					}
+				}
+			}
+		}
+
+
+		{
+			let mbuf_statistics = mbufs.current.unwrap_or_default();
+			let ssh_exec::netstat_m::MBufStatistics {
+				mbuf_current,
+				mbuf_total,
+				cluster_total,
+				cluster_max,
+				jumbo_total,
+				jumbo_max,
+				jumbo9_total,
+				jumbo9_max,
+				jumbo16_total,
+				jumbo16_max,
+				kib_in_use,
+				kib_total,
+				..
+			} = mbuf_statistics;
+
+			output.push(b'\n');
+			output.extend_from_slice(terminfo.clear_line());
+			output.extend_from_slice(b"MBUF usage    : ");
+
+			let clusters = [
+				("clusters", cluster_total, cluster_max),
+				("4k jumbo clusters", jumbo_total, jumbo_max),
+				("9k jumbo clusters", jumbo9_total, jumbo9_max),
+				("16k jumbo clusters", jumbo16_total, jumbo16_max),
+			];
+			for (i, (name, used, max)) in clusters.into_iter().enumerate() {
+				if i > 0 {
+					output.push(b'\n');
+					output.extend_from_slice(terminfo.clear_line());
+					output.extend_from_slice(b"                ");
+				}
+
+				let (usage_percent, usage_color) = usage(used as f32, max as f32);
+				write!(output, "\x1B[{usage_color}m{usage_percent:5.1} % ({used:7} / {max:7}) {name}\x1B[0m")?;
+			}
+
+			output.push(b'\n');
+			output.extend_from_slice(terminfo.clear_line());
+			write!(output, "                {mbuf_current} / {mbuf_total} mbufs in use, {} MiB / {} MiB allocated to network", kib_in_use / 1024, kib_total / 1024)?;
+
+			for mbuf::CounterRow { denied, counters, description } in mbuf::COUNTER_ROWS {
+				output.push(b'\n');
+				output.extend_from_slice(terminfo.clear_line());
+				output.extend_from_slice(if *denied { b"                denied  " } else { b"                delayed " });
+
+				for &counter in *counters {
+					let total = counter(&mbuf_statistics);
+					match mbufs.rate(counter, time_since_previous) {
+						Some(rate) if *denied => {
+							let counter_color = get_color_for_up_down(rate == 0.);
+							write!(output, " \x1B[{counter_color}m{rate:7.1}/s ({total:7})\x1B[0m")?;
+						},
+						Some(rate) => write!(output, " {rate:7.1}/s ({total:7})")?,
+						None => write!(output, "       ?/s ({total:7})")?,
+					}
+				}
+
+				write!(output, " {description}")?;
+			}
 		}
 
 
@@ -228,8 +533,8 @@ fn main() -> Result<(), Error> {
 			output.push(b'\n');
 			output.extend_from_slice(terminfo.clear_line());
 			output.extend_from_slice(b"Disk usage    : ");
-			let max_mount_point_len = filesystems.iter().map(|filesystem| filesystem.mounted_on.len()).max().unwrap_or_default();
-			for (i, filesystem) in filesystems.into_iter().enumerate() {
+			let max_name_len = filesystems.iter().map(|filesystem| filesystem.name.len()).max().unwrap_or_default();
+			for (i, filesystem) in filesystems.iter().enumerate() {
 				let filesystem_space_used = filesystem.used_blocks;
 				let filesystem_space_max = filesystem.total_blocks;
 				let (filesystem_space_usage_percent, filesystem_space_usage_color) = usage(filesystem_space_used as f32, filesystem_space_max as f32);
@@ -240,10 +545,102 @@ fn main() -> Result<(), Error> {
 				}
 
 				write!(output,
-					"\x1B[{filesystem_space_usage_color}m{:>max_mount_point_len$} : {filesystem_space_usage_percent:5.1} % of {}B\x1B[0m",
-					filesystem.mounted_on,
+					"\x1B[{filesystem_space_usage_color}m{:>max_name_len$} : {filesystem_space_usage_percent:5.1} % of {}B\x1B[0m",
+					filesystem.name,
 					HumanSizeBase10(filesystem.total_blocks as f32 * 1024.),
 				)?;
+
+				match (filesystem.growth_rate, filesystem.time_to_full()) {
+					(Some(growth_rate), Some(time_to_full)) => {
+						let time_to_full_color = get_color_for_up_down(time_to_full >= filesystems.time_to_full_warning());
+						let time_to_full = time_to_full.as_secs();
+						write!(
+							output,
+							" \x1B[{time_to_full_color}m{}B/h, full in {} days {:02}:{:02}\x1B[0m",
+							HumanSizeBase10(growth_rate * 1024. * 60. * 60.),
+							time_to_full / (24 * 60 * 60),
+							(time_to_full % (24 * 60 * 60)) / (60 * 60),
+							(time_to_full % (60 * 60)) / 60,
+						)?;
+					},
+
+					(Some(_), None) => output.extend_from_slice(b" not growing"),
+
+					(None, _) => output.extend_from_slice(b"     ?  B/h"),
+				}
+
+				if filesystems.show_inodes() {
+					let (filesystem_inodes_usage_percent, filesystem_inodes_usage_color) = usage(filesystem.used_inodes as f32, filesystem.total_inodes as f32);
+					write!(output,
+						" \x1B[{filesystem_inodes_usage_color}m{filesystem_inodes_usage_percent:5.1} % of {} inodes\x1B[0m",
+						HumanSizeBase10(filesystem.total_inodes as f32),
+					)?;
+				}
+			}
+		}
+
+
+		if show_zfs_pools {
+			output.push(b'\n');
+			output.extend_from_slice(terminfo.clear_line());
+			output.extend_from_slice(b"ZFS pools     : ");
+
+			let max_zfs_pool_name_len = zfs_pools.iter().map(|zfs_pool::ZfsPool { name, .. }| name.len()).max().unwrap_or_default();
+
+			for (i, zfs_pool) in zfs_pools.iter().enumerate() {
+				if i > 0 {
+					output.push(b'\n');
+					output.extend_from_slice(terminfo.clear_line());
+					output.extend_from_slice(b"                ");
+				}
+
+				let zfs_pool_health_color = get_color_for_up_down(zfs_pool.is_online());
+				let (_, zfs_pool_capacity_color) = usage(zfs_pool.capacity as f32, 100.);
+				write!(
+					output,
+					"\x1B[{zfs_pool_health_color}m{:>max_zfs_pool_name_len$} {:8}\x1B[0m \x1B[{zfs_pool_capacity_color}m{:3} % of {}B\x1B[0m",
+					zfs_pool.name,
+					zfs_pool.health,
+					zfs_pool.capacity,
+					HumanSizeBase10(zfs_pool.size as f32),
+				)?;
+				if let Some(fragmentation) = zfs_pool.fragmentation {
+					write!(output, ", {fragmentation:3} % fragmented")?;
+				}
+
+				output.push(b'\n');
+				output.extend_from_slice(terminfo.clear_line());
+				write!(output, "                {:max_zfs_pool_name_len$} {}", "", zfs_pool.scan)?;
+
+				// vdevs are indented by their depth in the pool, like `zpool status` does.
+				let max_vdev_name_len = zfs_pool.vdevs.iter().map(|ssh_exec::zpool_status::Vdev { name, depth, .. }| depth * 2 + name.len()).max().unwrap_or_default();
+
+				for ssh_exec::zpool_status::Vdev { name, depth, state, errors } in &zfs_pool.vdevs {
+					let name = format!("{:indent$}{name}", "", indent = depth * 2);
+
+					output.push(b'\n');
+					output.extend_from_slice(terminfo.clear_line());
+					write!(output, "                {:max_zfs_pool_name_len$} ", "")?;
+
+					match (state, errors) {
+						(Some(state), Some(ssh_exec::zpool_status::VdevErrors { read, write, checksum })) => {
+							let vdev_color = get_color_for_up_down(state == "ONLINE" && *read == 0 && *write == 0 && *checksum == 0);
+							write!(
+								output,
+								"\x1B[{vdev_color}m{name:max_vdev_name_len$} {state:8} {read:5} read {write:5} write {checksum:5} cksum errors\x1B[0m",
+							)?;
+						},
+
+						// Spares are `AVAIL`, or `INUSE` when they have replaced a failed disk.
+						(Some(state), None) => {
+							let vdev_color = get_color_for_up_down(state == "AVAIL");
+							write!(output, "\x1B[{vdev_color}m{name:max_vdev_name_len$} {state:8}\x1B[0m")?;
+						},
+
+						// Group headers like `logs`
+						(None, _) => write!(output, "{name}")?,
+					}
+				}
 			}
 		}
 
@@ -252,9 +649,14 @@ fn main() -> Result<(), Error> {
 			output.push(b'\n');
 			output.extend_from_slice(terminfo.clear_line());
 			output.extend_from_slice(b"SMART status  : ");
-			for (i, disk::Disk { name, serial_number, smart_passed, .. }) in disks.iter().enumerate() {
-				let disk_status_color = get_color_for_up_down(*smart_passed);
-				let disk_smart_status = if *smart_passed { "PASSED" } else { "FAILED" };
+			for (i, disk) in disks.iter().enumerate() {
+				let disk::Disk { name, serial_number, smart_passed, .. } = disk;
+				let serial_number = serial_number.as_deref().unwrap_or("n/a");
+				let (disk_status_color, disk_smart_status) = match smart_passed {
+					Some(true) => (get_color_for_up_down(true), "PASSED"),
+					Some(false) => (get_color_for_up_down(false), "FAILED"),
+					None => ("0", "n/a"),
+				};
 
 				if i > 0 {
 					output.push(b'\n');
@@ -262,7 +664,79 @@ fn main() -> Result<(), Error> {
 					output.extend_from_slice(b"                ");
 				}
 
-				write!(output, "\x1B[{disk_status_color}m{name:>max_disk_name_len$} {serial_number:max_disk_serial_number_len$} {disk_smart_status}\x1B[0m")?;
+				write!(output, "\x1B[{disk_status_color}m{name:>max_disk_name_len$} {serial_number:max_disk_serial_number_len$} {disk_smart_status:6}\x1B[0m")?;
+
+				match disk.io_rates(time_since_previous) {
+					Some(disk::IoRates { read_bytes_per_sec, written_bytes_per_sec, reads_per_sec, writes_per_sec, busy_percent, latency }) => {
+						let busy_color = get_color_for_usage(busy_percent);
+						write!(
+							output,
+							" {}B/s read {}B/s write {reads_per_sec:6.1} r/s {writes_per_sec:6.1} w/s \x1B[{busy_color}m{busy_percent:5.1} % busy\x1B[0m",
+							HumanSizeBase10(read_bytes_per_sec),
+							HumanSizeBase10(written_bytes_per_sec),
+						)?;
+						if let Some(latency) = latency {
+							write!(output, " {:6.2} ms", latency.as_secs_f32() * 1000.)?;
+						}
+					},
+
+					None => output.extend_from_slice(b"     ?  B/s read     ?  B/s write      ? r/s      ? w/s     ? % busy"),
+				}
+
+				output.push(b'\n');
+				output.extend_from_slice(terminfo.clear_line());
+				write!(output, "                {:max_disk_name_len$}", "")?;
+
+				let ssh_exec::smartctl_a::Health {
+					power_on_hours,
+					reallocated_sectors,
+					pending_sectors,
+					uncorrectable_sectors,
+					media_errors,
+					percentage_used,
+					critical_warning,
+					available_spare,
+				} = disk.health.unwrap_or_default();
+
+				if let Some(power_on_hours) = power_on_hours {
+					write!(output, " {power_on_hours} h")?;
+				}
+
+				// Error counters are highlighted if they're non-zero, and more so if they increased since the previous refresh.
+				let error_counters: [(&str, disk::Counter, Option<u64>); 4] = [
+					("reallocated", |health| health.reallocated_sectors, reallocated_sectors),
+					("pending", |health| health.pending_sectors, pending_sectors),
+					("uncorrectable", |health| health.uncorrectable_sectors, uncorrectable_sectors),
+					("media errors", |health| health.media_errors, media_errors),
+				];
+				for (counter_name, counter, value) in error_counters {
+					let Some(value) = value else { continue; };
+					let counter_color =
+						if disk.increased(counter) { "0;31" }
+						else if value > 0 { "1;33" }
+						else { "1;32" };
+					write!(output, " \x1B[{counter_color}m{value} {counter_name}\x1B[0m")?;
+				}
+
+				if let Some(critical_warning) = critical_warning {
+					let critical_warning_color = get_color_for_up_down(critical_warning == 0);
+					write!(output, " \x1B[{critical_warning_color}mcritical warning 0x{critical_warning:02x}\x1B[0m")?;
+				}
+
+				if let Some((available_spare, available_spare_threshold)) = available_spare {
+					let available_spare_color = get_color_for_up_down(available_spare > available_spare_threshold);
+					write!(output, " \x1B[{available_spare_color}m{available_spare} % spare\x1B[0m")?;
+				}
+
+				if let Some(percentage_used) = percentage_used {
+					let percentage_used_color = get_color_for_usage(percentage_used as f32);
+					write!(output, " \x1B[{percentage_used_color}m{percentage_used} % used\x1B[0m")?;
+				}
+
+				if let Some(ssh_exec::smartctl_a::SelfTest { result, passed }) = &disk.self_test {
+					let self_test_color = get_color_for_up_down(*passed);
+					write!(output, " \x1B[{self_test_color}mlast self-test: {result}\x1B[0m")?;
+				}
 			}
 		}
 
@@ -273,17 +747,17 @@ fn main() -> Result<(), Error> {
 			output.extend_from_slice(b"Temperatures  : ");
 
 			let thermal_sensors =
-				temperature_sysctls.iter().map(|temperature_sysctl::TemperatureSysctl { name, value }| {
-					let thermal_sensor_value = *value as f32 / 10. - 273.15;
-					(name, thermal_sensor_value)
+				temperature_sysctls.iter().map(|temperature_sysctl| {
+					let thermal_sensor_value = temperature_sysctl.celsius();
+					(&temperature_sysctl.name, Some(thermal_sensor_value))
 				})
-				.chain(disks.iter().map(|disk::Disk { name, temperature, .. }| {
-					let thermal_sensor_value = *temperature as f32;
+				.chain(disks.iter().filter(|disk| disk.has_smart()).map(|disk::Disk { name, temperature, .. }| {
+					let thermal_sensor_value = temperature.map(|temperature| temperature as f32);
 					(name, thermal_sensor_value)
 				}));
 
 			for (i, (thermal_sensor_name, thermal_sensor_value)) in thermal_sensors.enumerate() {
-				let thermal_sensor_color = get_color_for_temperature(thermal_sensor_value);
+				let thermal_sensor_name = config.temperatures.display_name(thermal_sensor_name);
 
 				if i > 0 {
 					output.push(b'\n');
@@ -291,7 +765,66 @@ fn main() -> Result<(), Error> {
 					output.extend_from_slice(b"                ");
 				}
 
-				write!(output, "\x1B[{thermal_sensor_color}m{thermal_sensor_name:>max_thermal_sensor_name_len$} : {thermal_sensor_value:5.1} \u{00B0}C\x1B[0m")?;
+				if let Some(thermal_sensor_value) = thermal_sensor_value {
+					let thermal_sensor_color = get_color_for_temperature(thermal_sensor_value);
+					if config.temperatures.fahrenheit {
+						let thermal_sensor_value = celsius_to_fahrenheit(thermal_sensor_value);
+						write!(output, "\x1B[{thermal_sensor_color}m{thermal_sensor_name:>max_thermal_sensor_name_len$} : {thermal_sensor_value:5.1} \u{00B0}F\x1B[0m")?;
+					}
+					else {
+						write!(output, "\x1B[{thermal_sensor_color}m{thermal_sensor_name:>max_thermal_sensor_name_len$} : {thermal_sensor_value:5.1} \u{00B0}C\x1B[0m")?;
+					}
+				}
+				else {
+					write!(output, "{thermal_sensor_name:>max_thermal_sensor_name_len$} :   n/a")?;
+				}
+			}
+
+			// IPMI sensors are colored according to the status that the BMC computed from its own thresholds.
+			let num_thermal_sensors = temperature_sysctls.len() + disks.iter().filter(|disk| disk.has_smart()).count();
+			for (i, sensor) in ipmi_sensors.iter().flat_map(ipmi::IpmiSensors::iter).enumerate() {
+				if num_thermal_sensors + i > 0 {
+					output.push(b'\n');
+					output.extend_from_slice(terminfo.clear_line());
+					output.extend_from_slice(b"                ");
+				}
+
+				let ssh_exec::ipmitool_sensor::Sensor { name, value, unit, status, description, lower_critical, upper_critical } = sensor;
+				let name = config.temperatures.display_name(name);
+
+				let sensor_color = match &**status {
+					"ok" => get_color_for_up_down(true),
+					"nc" => "1;33",
+					"cr" | "nr" => get_color_for_up_down(false),
+					_ => "0",
+				};
+
+				let Some(mut value) = *value else {
+					let status = description.as_deref().unwrap_or(status);
+					write!(output, "\x1B[{sensor_color}m{name:>max_thermal_sensor_name_len$} : {status}\x1B[0m")?;
+					continue;
+				};
+
+				let (mut lower_critical, mut upper_critical) = (*lower_critical, *upper_critical);
+				let unit = match &**unit {
+					"degrees C" if config.temperatures.fahrenheit => {
+						value = celsius_to_fahrenheit(value);
+						lower_critical = lower_critical.map(celsius_to_fahrenheit);
+						upper_critical = upper_critical.map(celsius_to_fahrenheit);
+						"\u{00B0}F"
+					},
+					"degrees C" => "\u{00B0}C",
+					unit => unit,
+				};
+
+				write!(output, "\x1B[{sensor_color}m{name:>max_thermal_sensor_name_len$} : {value:5.1} {unit}")?;
+				match (lower_critical, upper_critical) {
+					(Some(lower_critical), Some(upper_critical)) => write!(output, " (critical < {lower_critical:.1} or > {upper_critical:.1})")?,
+					(Some(lower_critical), None) => write!(output, " (critical < {lower_critical:.1})")?,
+					(None, Some(upper_critical)) => write!(output, " (critical > {upper_critical:.1})")?,
+					(None, None) => (),
+				}
+				output.extend_from_slice(b"\x1B[0m");
 			}
 		}
 
@@ -301,43 +834,171 @@ fn main() -> Result<(), Error> {
 			output.extend_from_slice(terminfo.clear_line());
 			output.extend_from_slice(b"Interfaces    : ");
 
-			for (i, (interface_name, interface)) in interfaces.iter_mut().enumerate() {
+			// Children like VLANs, LAGG ports and bridge members are indented under their parents.
+			// The tree can change when ifconfig reports new parents, so the name width is recalculated every time.
+			let max_interface_name_len =
+				interfaces.iter()
+				.flat_map(|(interface_name, depth, interface)|
+					std::iter::once(depth * 2 + interface_display_name(&interface_display_names, interface_name).len())
+					.chain(interface.ifconfig.lagg_ports.iter().map(move |lagg_port| (depth + 1) * 2 + lagg_port.name.len())))
+				.max()
+				.unwrap_or_default();
+
+			for (i, (interface_name, depth, interface)) in interfaces.iter().enumerate() {
 				if i > 0 {
 					output.push(b'\n');
 					output.extend_from_slice(terminfo.clear_line());
 					output.extend_from_slice(b"                ");
 				}
 
-				let interface_status_color = get_color_for_up_down(interface.error.is_none());
+				let interface_enabled = !disabled_interfaces.contains(interface_name);
+				let interface_status = interface.status();
+				let interface_status_color = get_color_for_interface_status(interface_enabled, &interface_status);
 
-				write!(output, "\x1B[{interface_status_color}m{interface_name:>max_interface_name_len$} : ")?;
+				// Top-level names are right-aligned like in the other sections. Children are indented under their parents instead.
+				let interface_name = interface_display_name(&interface_display_names, interface_name);
+				let interface_name =
+					if depth == 0 { format!("{interface_name:>max_interface_name_len$}") }
+					else { format!("{:indent$}{interface_name:width$}", "", indent = depth * 2, width = max_interface_name_len - depth * 2) };
 
-				if let Some(interface_error) = &interface.error {
-					write!(output, "{interface_error:30}")?;
-				}
-				else {
-					match interface.speed(time_since_previous) {
-						Some((interface_received_speed, interface_sent_speed)) =>
-							write!(output, "{}b/s down {}b/s up ", HumanSizeBase10(interface_received_speed), HumanSizeBase10(interface_sent_speed))?,
+				let packet_rates = interface.packet_rates(time_since_previous);
 
-						None =>
-							output.extend_from_slice(b"    ?  b/s down     ?  b/s up "),
-					}
-				}
+				// The first five lines show the status or speed, the packet rates, the error and drop rates,
+				// the link media and utilization, and the MAC address and MTU.
+				// The addresses are shown to the right of them, and may need more lines.
+				let num_lines = interface.addresses().count().max(5);
+				let mut addresses = interface.addresses();
 
-				for (i, address) in interface.addresses().enumerate() {
-					if i > 0 {
+				for line in 0..num_lines {
+					if line == 0 {
+						write!(output, "\x1B[{interface_status_color}m{interface_name} : ")?;
+					}
+					else {
 						output.push(b'\n');
 						output.extend_from_slice(terminfo.clear_line());
-						write!(
-							output,
-							"                \x1B[{interface_status_color}m{:>max_interface_name_len$}                                 ",
-							"",
-						)?;
+						write!(output, "                \x1B[{interface_status_color}m{:>max_interface_name_len$}   ", "")?;
+					}
+
+					match (line, &interface_status, packet_rates) {
+						(0, _, _) if !interface_enabled => write!(output, "{:30}", "disabled")?,
+
+						(0, ssh_exec::ifconfig::Status::Active, _) => match interface.speed(time_since_previous) {
+							Some((interface_received_speed, interface_sent_speed)) =>
+								write!(output, "{}b/s down {}b/s up ", HumanSizeBase10(interface_received_speed), HumanSizeBase10(interface_sent_speed))?,
+
+							None =>
+								output.extend_from_slice(b"    ?  b/s down     ?  b/s up "),
+						},
+
+						(0, interface_status, _) => write!(output, "{interface_status:30}")?,
+
+						(1, _, Some(interface::PacketRates { received_packets, sent_packets, .. })) =>
+							write!(output, "{}p/s down {}p/s up ", HumanSizeBase10(received_packets), HumanSizeBase10(sent_packets))?,
+
+						(1, _, None) =>
+							output.extend_from_slice(b"    ?  p/s down     ?  p/s up "),
+
+						// Any errors or drops are highlighted, since they indicate problems like a bad cable or a duplex mismatch.
+						(2, _, Some(interface::PacketRates { errors, drops, .. })) => {
+							let errors_color = if errors > 0. { get_color_for_up_down(false) } else { interface_status_color };
+							let drops_color = if drops > 0. { get_color_for_up_down(false) } else { interface_status_color };
+							write!(
+								output,
+								"\x1B[{errors_color}m{}err/s \x1B[{drops_color}m{}drop/s\x1B[{interface_status_color}m    ",
+								HumanSizeBase10(errors),
+								HumanSizeBase10(drops),
+							)?;
+						},
+
+						(2, _, None) =>
+							output.extend_from_slice(b"    ?  err/s     ?  drop/s    "),
+
+						(3, _, _) => {
+							let media = interface.ifconfig.media.as_ref().map_or("", |media| &media.description);
+							write!(output, "{media:21.21} ")?;
+							match interface.utilization(time_since_previous) {
+								Some(utilization) => {
+									let utilization_color = get_color_for_usage(utilization);
+									write!(output, "\x1B[{utilization_color}m{utilization:5.1} %\x1B[{interface_status_color}m ")?;
+								},
+								None => output.extend_from_slice(b"        "),
+							}
+						},
+
+						(4, _, _) => {
+							let mac = interface.ifconfig.mac.as_deref().unwrap_or_default();
+							match interface.ifconfig.mtu {
+								Some(mtu) => write!(output, "{mac:17} mtu {mtu:<8}")?,
+								None => write!(output, "{mac:30}")?,
+							}
+						},
+
+						_ => write!(output, "{:30}", "")?,
+					}
+
+					if let Some(address) = addresses.next() {
+						write!(output, "{address}")?;
 					}
 
-					write!(output, "{address}\x1B[0m")?;
+					output.extend_from_slice(b"\x1B[0m");
+				}
+
+				let lagg_proto = interface.ifconfig.lagg_proto.as_deref().unwrap_or_default();
+				for lagg_port in &interface.ifconfig.lagg_ports {
+					let lagg_port_color = get_color_for_up_down(interface.is_lagg_port_healthy(lagg_port));
+					let lagg_port_name = format!("{:indent$}{}", "", lagg_port.name, indent = (depth + 1) * 2);
+					output.push(b'\n');
+					output.extend_from_slice(terminfo.clear_line());
+					write!(
+						output,
+						"                \x1B[{lagg_port_color}m{lagg_port_name:max_interface_name_len$} : {lagg_proto} {}\x1B[0m",
+						lagg_port.flags.join(","),
+					)?;
+				}
+			}
+		}
+
+
+		if let Some(traffic) = &traffic {
+			output.push(b'\n');
+			output.extend_from_slice(terminfo.clear_line());
+			output.extend_from_slice(b"Traffic       : ");
+
+			let max_traffic_interface_name_len = traffic.iter().map(|(name, _, _)| interface_display_name(&interface_display_names, name).len()).max().unwrap_or_default();
+
+			for (i, (name, totals, config::TrafficInterface { cap, warning, critical })) in traffic.iter().enumerate() {
+				if i > 0 {
+					output.push(b'\n');
+					output.extend_from_slice(terminfo.clear_line());
+					output.extend_from_slice(b"                ");
+				}
+
+				let name = interface_display_name(&interface_display_names, name);
+
+				let cap = cap.map(|cap| cap * 1e9);
+				let month_usage = cap.map(|cap| (totals.month_received + totals.month_sent) as f32 / cap * 100.);
+
+				let traffic_color = match month_usage {
+					Some(month_usage) if month_usage >= *critical => get_color_for_up_down(false),
+					Some(month_usage) if month_usage >= *warning => "1;33",
+					Some(_) => get_color_for_up_down(true),
+					None => "0",
+				};
+
+				write!(
+					output,
+					"\x1B[{traffic_color}m{name:>max_traffic_interface_name_len$} : today {}B down {}B up, month {}B down {}B up",
+					HumanSizeBase10(totals.day_received as f32),
+					HumanSizeBase10(totals.day_sent as f32),
+					HumanSizeBase10(totals.month_received as f32),
+					HumanSizeBase10(totals.month_sent as f32),
+				)?;
+
+				if let (Some(cap), Some(month_usage)) = (cap, month_usage) {
+					write!(output, " of {}B ({month_usage:5.1} %)", HumanSizeBase10(cap))?;
 				}
+
+				output.extend_from_slice(b"\x1B[0m");
 			}
 		}
 
@@ -347,23 +1008,72 @@ fn main() -> Result<(), Error> {
 			output.extend_from_slice(terminfo.clear_line());
 			output.extend_from_slice(b"Gateways      : ");
 
-			for (i, (name, gateway)) in gateways.iter().enumerate() {
+			for (i, (name, gateway_config, gateway)) in gateways.iter().enumerate() {
 				if i > 0 {
 					output.push(b'\n');
 					output.extend_from_slice(terminfo.clear_line());
 					output.extend_from_slice(b"                ");
 				}
 
+				let monitor = gateway_config.monitor.as_deref().unwrap_or_default();
+
 				match gateway {
-					Some(gateway::Gateway { latency_average, latency_stddev, ping_packet_loss }) => write!(
-						output,
-						"{name:>max_gateway_name_len$} : {:6.1} ms ({:6.1} ms) {ping_packet_loss:3} %",
-						latency_average.as_secs_f32() * 1000.,
-						latency_stddev.as_secs_f32() * 1000.,
-					)?,
+					Some(gateway::Gateway { latency_average, latency_stddev, ping_packet_loss, status }) => {
+						let gateway_color = match status {
+							gateway::Status::Online => get_color_for_up_down(true),
+							gateway::Status::Delay | gateway::Status::Loss => "1;33",
+							gateway::Status::Down => get_color_for_up_down(false),
+						};
+
+						write!(
+							output,
+							"\x1B[{gateway_color}m{name:>max_gateway_name_len$} : {status:11} {:6.1} ms ({:6.1} ms) {ping_packet_loss:3} % {monitor:max_gateway_monitor_len$}",
+							latency_average.as_secs_f32() * 1000.,
+							latency_stddev.as_secs_f32() * 1000.,
+						)?;
+					},
+
+					None => write!(output, "{name:>max_gateway_name_len$} : {:39} {monitor:max_gateway_monitor_len$}", "dpinger is not running")?,
+				}
+
+				// The configured default gateway, and the gateway that the system's default route currently goes through.
+				// These differ when OPNsense has failed over to another gateway.
+				if gateway_config.is_default {
+					output.extend_from_slice(b" default");
+				}
+				if gateways.is_default_route(gateway_config) {
+					output.extend_from_slice(if gateway_config.is_default { b", default route" } else { b" default route" });
+				}
+
+				output.extend_from_slice(b"\x1B[0m");
+			}
+		}
+
+
+		if gateways.groups().next().is_some() {
+			output.push(b'\n');
+			output.extend_from_slice(terminfo.clear_line());
+			output.extend_from_slice(b"Gateway groups: ");
+
+			for (i, (group, members)) in gateways.groups().enumerate() {
+				if i > 0 {
+					output.push(b'\n');
+					output.extend_from_slice(terminfo.clear_line());
+					output.extend_from_slice(b"                ");
+				}
+
+				write!(output, "{:>max_gateway_group_name_len$} :", group.name)?;
 
-					None => write!(output, "{name:>max_gateway_name_len$} : dpinger is not running")?,
+				for (name, tier, status) in members {
+					let member_color = match status {
+						gateway::MemberStatus::Active => get_color_for_up_down(true),
+						gateway::MemberStatus::Standby => "0",
+						gateway::MemberStatus::Unusable => get_color_for_up_down(false),
+					};
+					write!(output, " \x1B[{member_color}m{name} (tier {tier}, {status})\x1B[0m")?;
 				}
+
+				write!(output, " trigger: {}", group.trigger)?;
 			}
 		}
 
@@ -422,7 +1132,7 @@ fn main() -> Result<(), Error> {
 						output,
 						"\x1B[{firewall_log_color}m{} {:max_firewall_log_interface_name_len$} {}      icmp <- {source}\x1B[0m",
 						firewall_log.timestamp,
-						firewall_log.interface,
+						interface_display_name(&interface_display_names, &firewall_log.interface),
 						firewall_log.action,
 					)?,
 
@@ -430,7 +1140,7 @@ fn main() -> Result<(), Error> {
 						output,
 						"\x1B[{firewall_log_color}m{} {:max_firewall_log_interface_name_len$} {} {:5}/tcp <- {}\x1B[0m",
 						firewall_log.timestamp,
-						firewall_log.interface,
+						interface_display_name(&interface_display_names, &firewall_log.interface),
 						firewall_log.action,
 						destination.port(),
 						FirewallLogsSource(source),
@@ -440,7 +1150,7 @@ fn main() -> Result<(), Error> {
 						output,
 						"\x1B[{firewall_log_color}m{} {:max_firewall_log_interface_name_len$} {} {:5}/udp <- {}\x1B[0m",
 						firewall_log.timestamp,
-						firewall_log.interface,
+						interface_display_name(&interface_display_names, &firewall_log.interface),
 						firewall_log.action,
 						destination.port(),
 						FirewallLogsSource(source),
@@ -547,9 +1257,15 @@ macro_rules! impl_parse {
 	};
 }
 
+impl_parse! { i32 }
+impl_parse! { i64 }
 impl_parse! { u32 }
 impl_parse! { u64 }
 
+fn interface_display_name<'a>(interface_display_names: &'a std::collections::BTreeMap<&str, String>, name: &'a str) -> &'a str {
+	interface_display_names.get(name).map_or(name, AsRef::as_ref)
+}
+
 fn usage(used: f32, max: f32) -> (f32, &'static str) {
 	let usage_percent = used * 100. / max;
 	let usage_color = get_color_for_usage(usage_percent);
@@ -568,6 +1284,10 @@ fn get_color_for_temperature(temp: f32) -> &'static str {
 	}
 }
 
+fn celsius_to_fahrenheit(temp: f32) -> f32 {
+	temp * 9. / 5. + 32.
+}
+
 fn get_color_for_up_down(is_up: bool) -> &'static str {
 	if is_up {
 		"1;32"
@@ -577,6 +1297,17 @@ fn get_color_for_up_down(is_up: bool) -> &'static str {
 	}
 }
 
+fn get_color_for_interface_status(is_enabled: bool, status: &ssh_exec::ifconfig::Status) -> &'static str {
+	match status {
+		_ if !is_enabled => "2",
+		ssh_exec::ifconfig::Status::Active => get_color_for_up_down(true),
+		ssh_exec::ifconfig::Status::AdminDown => "1;33",
+		ssh_exec::ifconfig::Status::NoCarrier => get_color_for_up_down(false),
+		ssh_exec::ifconfig::Status::NotPresent => "0;33",
+		ssh_exec::ifconfig::Status::Other(_) => "1;35",
+	}
+}
+
 fn get_color_for_usage(usage: f32) -> &'static str {
 	match usage {
 		usage if usage < 5. => "0;34",
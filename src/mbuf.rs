@@ -0,0 +1,88 @@
+pub(crate) type Counter = fn(&crate::ssh_exec::netstat_m::MBufStatistics) -> u64;
+
+#[derive(Debug)]
+pub(crate) struct CounterRow {
+	// Denied requests mean dropped packets, so they are highlighted when they increase.
+	// Delayed requests are slow but not dropped.
+	pub(crate) denied: bool,
+	pub(crate) counters: &'static [Counter],
+	pub(crate) description: &'static str,
+}
+
+pub(crate) const COUNTER_ROWS: &[CounterRow] = &[
+	CounterRow {
+		denied: true,
+		counters: &[
+			|statistics| statistics.mbuf_failures,
+			|statistics| statistics.cluster_failures,
+			|statistics| statistics.packet_failures,
+		],
+		description: "mbufs/clusters/packets",
+	},
+	CounterRow {
+		denied: true,
+		counters: &[
+			|statistics| statistics.jumbop_failures,
+			|statistics| statistics.jumbo9_failures,
+			|statistics| statistics.jumbo16_failures,
+		],
+		description: "4k/9k/16k jumbo clusters",
+	},
+	CounterRow {
+		denied: true,
+		counters: &[|statistics| statistics.sfbufs_alloc_failed],
+		description: "sfbufs",
+	},
+	CounterRow {
+		denied: false,
+		counters: &[
+			|statistics| statistics.mbuf_sleeps,
+			|statistics| statistics.cluster_sleeps,
+			|statistics| statistics.packet_sleeps,
+		],
+		description: "mbufs/clusters/packets",
+	},
+	CounterRow {
+		denied: false,
+		counters: &[
+			|statistics| statistics.jumbop_sleeps,
+			|statistics| statistics.jumbo9_sleeps,
+			|statistics| statistics.jumbo16_sleeps,
+		],
+		description: "4k/9k/16k jumbo clusters",
+	},
+	CounterRow {
+		denied: false,
+		counters: &[|statistics| statistics.sfbufs_alloc_wait],
+		description: "sfbufs",
+	},
+];
+
+#[derive(Debug)]
+pub(crate) struct MBufs {
+	previous: Option<crate::ssh_exec::netstat_m::MBufStatistics>,
+	pub(crate) current: Option<crate::ssh_exec::netstat_m::MBufStatistics>,
+}
+
+impl MBufs {
+	pub(crate) fn new() -> Self {
+		MBufs {
+			previous: None,
+			current: None,
+		}
+	}
+
+	pub(crate) fn update(&mut self, session: &ssh2::Session) -> Result<(), crate::Error> {
+		let current = crate::ssh_exec::netstat_m::get_mbuf_statistics(session)?;
+		self.previous = self.current.replace(current);
+		Ok(())
+	}
+
+	// Returns None for the first sample.
+	pub(crate) fn rate(&self, counter: Counter, time_since_previous: std::time::Duration) -> Option<f32> {
+		let previous = counter(self.previous.as_ref()?);
+		let current = counter(self.current.as_ref()?);
+		let diff = current.checked_sub(previous)?;
+		Some(diff as f32 / time_since_previous.as_secs_f32())
+	}
+}
@@ -1,28 +1,93 @@
 #[derive(Clone, Copy, Debug)]
 pub(crate) struct Memory {
-	pub(crate) physical: crate::c_ulong,
 	pub(crate) num_pages: crate::c_uint,
-	pub(crate) used_pages: crate::c_uint,
+	page_size: crate::c_int,
+
+	pub(crate) active_pages: crate::c_uint,
+	pub(crate) inactive_pages: crate::c_uint,
+	pub(crate) laundry_pages: crate::c_uint,
+	pub(crate) wired_pages: crate::c_uint,
+	pub(crate) free_pages: crate::c_uint,
+
+	pub(crate) arc: Option<Arc>,
+}
+
+// All values are in bytes.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Arc {
+	pub(crate) size: u64,
+	pub(crate) target_min: u64,
+	pub(crate) target_max: u64,
 }
 
 impl Memory {
+	// ARC statistics are only available if the ZFS module is loaded.
+	pub(crate) fn with_arc(mut self, sysctl_names: &[String]) -> Self {
+		if sysctl_names.iter().any(|sysctl_name| sysctl_name == "kstat.zfs.misc.arcstats.size") {
+			self.arc = Some(Default::default());
+		}
+		self
+	}
+
 	pub(crate) fn update(&mut self, reader: &mut impl std::io::Read) -> Result<(), crate::Error> {
-		let inactive_pages: crate::c_uint = crate::Parse::parse(reader)?;
-		let cache_pages: crate::c_uint = crate::Parse::parse(reader)?;
-		let free_pages: crate::c_uint = crate::Parse::parse(reader)?;
-		self.used_pages = self.num_pages - inactive_pages - cache_pages - free_pages;
+		self.active_pages = crate::Parse::parse(reader)?;
+		self.inactive_pages = crate::Parse::parse(reader)?;
+		self.laundry_pages = crate::Parse::parse(reader)?;
+		self.wired_pages = crate::Parse::parse(reader)?;
+		self.free_pages = crate::Parse::parse(reader)?;
+
+		if let Some(arc) = &mut self.arc {
+			arc.size = crate::Parse::parse(reader)?;
+			arc.target_min = crate::Parse::parse(reader)?;
+			arc.target_max = crate::Parse::parse(reader)?;
+		}
+
 		Ok(())
 	}
+
+	// The ARC lives in wired memory, but the part of it above its minimum target is reclaimed by the kernel under memory pressure,
+	// so it is not counted as used.
+	pub(crate) fn used(&self) -> u64 {
+		let used = self.pages_to_bytes(self.active_pages) + self.pages_to_bytes(self.laundry_pages) + self.pages_to_bytes(self.wired_pages);
+		let arc_reclaimable = self.arc.map_or(0, |Arc { size, target_min, .. }| size.saturating_sub(target_min));
+		used.saturating_sub(arc_reclaimable)
+	}
+
+	pub(crate) fn total(&self) -> u64 {
+		self.pages_to_bytes(self.num_pages)
+	}
+
+	pub(crate) fn pages_to_bytes(&self, pages: crate::c_uint) -> u64 {
+		#[allow(clippy::cast_sign_loss)] // page size is always positive
+		let page_size = self.page_size as u64;
+		u64::from(pages) * page_size
+	}
 }
 
 impl crate::Parse for Memory {
 	fn parse<R>(reader: &mut R) -> std::io::Result<Self> where R: std::io::Read {
-		let physical = crate::Parse::parse(reader)?;
 		let num_pages = crate::Parse::parse(reader)?;
+		let page_size = crate::Parse::parse(reader)?;
 		Ok(crate::memory::Memory {
-			physical,
 			num_pages,
-			used_pages: 0,
+			page_size,
+
+			active_pages: 0,
+			inactive_pages: 0,
+			laundry_pages: 0,
+			wired_pages: 0,
+			free_pages: 0,
+
+			arc: None,
 		})
 	}
 }
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Swap {
+	// In KiB
+	pub(crate) total: u64,
+
+	// In KiB
+	pub(crate) used: u64,
+}
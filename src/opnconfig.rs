@@ -3,11 +3,103 @@ pub(crate) struct OpnConfig {
 	pub(crate) gateway_interfaces: std::collections::BTreeSet<String>,
 	pub(crate) other_interfaces: Vec<String>,
 	pub(crate) gateways: Vec<Gateway>,
+	pub(crate) gateway_groups: Vec<GatewayGroup>,
+
+	// Device name (`igb0`) -> logical name and description
+	pub(crate) interface_descriptions: std::collections::BTreeMap<String, InterfaceDescription>,
+
+	// Device name of a VLAN, LAGG member or bridge member -> device name of its parent interface
+	pub(crate) interface_parents: std::collections::BTreeMap<String, String>,
+
+	// Device name -> configured addresses
+	pub(crate) interface_addresses: std::collections::BTreeMap<String, InterfaceAddresses>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct InterfaceAddresses {
+	// A static IPv4 address, or a type like `dhcp`
+	pub(crate) ipaddr: Option<String>,
+
+	// A static IPv6 address, or a type like `dhcp6`, `slaac` or `track6`
+	pub(crate) ipaddrv6: Option<String>,
+
+	// IP aliases and proxy ARP addresses. CARP addresses are identified by their vhid in ifconfig instead.
+	pub(crate) virtual_ips: Vec<std::net::IpAddr>,
+}
+
+#[derive(Debug)]
+pub(crate) struct InterfaceDescription {
+	// The logical name of the interface, like `lan` or `opt3`.
+	pub(crate) name: String,
+
+	// The user-provided description of the interface, like `GUEST`.
+	pub(crate) descr: Option<String>,
+
+	pub(crate) enabled: bool,
+}
+
+impl InterfaceDescription {
+	// The name that the OPNsense web UI uses for the interface.
+	pub(crate) fn display_name(&self) -> std::borrow::Cow<'_, str> {
+		match &self.descr {
+			Some(descr) => descr.into(),
+			None => self.name.to_uppercase().into(),
+		}
+	}
 }
 
 #[derive(Debug)]
 pub(crate) struct Gateway {
 	pub(crate) name: String,
+
+	// Device name of the interface the gateway is on
+	pub(crate) interface: String,
+
+	// The gateway's IP, or `None` for dynamic gateways whose IP is assigned by DHCP, PPPoE, etc.
+	pub(crate) address: Option<std::net::IpAddr>,
+
+	pub(crate) is_ipv6: bool,
+
+	// The IP that dpinger pings, which is the gateway's own IP unless a separate monitor IP is configured.
+	pub(crate) monitor: Option<String>,
+
+	pub(crate) is_default: bool,
+
+	pub(crate) latency_low: std::time::Duration,
+	pub(crate) latency_high: std::time::Duration,
+	pub(crate) loss_low: u64,
+	pub(crate) loss_high: u64,
+}
+
+#[derive(Debug)]
+pub(crate) struct GatewayGroup {
+	pub(crate) name: String,
+
+	// Gateway names and their tiers, sorted by tier. Lower tiers are preferred.
+	pub(crate) members: Vec<(String, u32)>,
+
+	pub(crate) trigger: GatewayGroupTrigger,
+}
+
+// When a member of a gateway group is considered unusable and traffic fails over to the next tier.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum GatewayGroupTrigger {
+	Down,
+	DownOrLoss,
+	DownOrLatency,
+	DownOrLossOrLatency,
+}
+
+impl std::fmt::Display for GatewayGroupTrigger {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let s = match self {
+			GatewayGroupTrigger::Down => "member down",
+			GatewayGroupTrigger::DownOrLoss => "packet loss",
+			GatewayGroupTrigger::DownOrLatency => "high latency",
+			GatewayGroupTrigger::DownOrLossOrLatency => "packet loss or high latency",
+		};
+		f.pad(s)
+	}
 }
 
 impl OpnConfig {
@@ -19,28 +111,132 @@ impl OpnConfig {
 		let mut gateway_interfaces: std::collections::BTreeSet<_> = Default::default();
 		let mut gateways = vec![];
 
-		for (&gateway_name, &gateway_interface) in &opnconfig.gateways.0 {
-			let &r#if =
+		for (&gateway_name, gateway_item) in &opnconfig.gateways.items {
+			let &GatewayItem { interface: gateway_interface, gateway, ipprotocol, monitor, defaultgw, latencylow, latencyhigh, losslow, losshigh, .. } = gateway_item;
+
+			let Interface { r#if, .. } =
 				opnconfig.interfaces.0
 				.get(gateway_interface)
 				.ok_or_else(|| format!("gateway {gateway_name} is defined on interface {gateway_interface} but this interface does not exist"))?;
-			gateway_interfaces.insert(r#if.to_owned());
+			gateway_interfaces.insert((*r#if).to_owned());
+
+			// Dynamic gateways have `dynamic` instead of an IP.
+			let address: Option<std::net::IpAddr> = gateway.and_then(|gateway| gateway.parse().ok());
+			let monitor = monitor.or(gateway).filter(|monitor| monitor.parse::<std::net::IpAddr>().is_ok());
+
+			// Thresholds are in milliseconds and percent. These are the defaults that OPNsense uses when they are not set.
+			let parse_threshold = |value: Option<&str>, default| value.and_then(|value| value.parse().ok()).unwrap_or(default);
 
 			gateways.push(Gateway {
 				name: gateway_name.to_owned(),
+				interface: (*r#if).to_owned(),
+				address,
+				is_ipv6: ipprotocol == Some("inet6") || address.is_some_and(|address| address.is_ipv6()),
+				monitor: monitor.map(ToOwned::to_owned),
+				is_default: defaultgw,
+				latency_low: std::time::Duration::from_millis(parse_threshold(latencylow, 200)),
+				latency_high: std::time::Duration::from_millis(parse_threshold(latencyhigh, 500)),
+				loss_low: parse_threshold(losslow, 10),
+				loss_high: parse_threshold(losshigh, 20),
 			});
 		}
 
-		for gateway_interface in opnconfig.gateways.0.into_values() {
+		let gateway_groups =
+			opnconfig.gateways.groups.iter()
+			.map(|GatewayGroupItem { name, items, trigger }| {
+				// Each item is `gateway|tier|virtual IP`
+				let mut members: Vec<_> =
+					items.iter()
+					.filter_map(|item| {
+						let mut parts = item.split('|');
+						let gateway = parts.next()?;
+						let tier = parts.next()?.parse().ok()?;
+						Some((gateway.to_owned(), tier))
+					})
+					.collect();
+				members.sort_by_key(|&(_, tier)| tier);
+
+				let trigger = match *trigger {
+					Some("downloss") => GatewayGroupTrigger::DownOrLoss,
+					Some("downlatency") => GatewayGroupTrigger::DownOrLatency,
+					Some("downlosslatency") => GatewayGroupTrigger::DownOrLossOrLatency,
+					_ => GatewayGroupTrigger::Down,
+				};
+
+				GatewayGroup {
+					name: (*name).to_owned(),
+					members,
+					trigger,
+				}
+			})
+			.collect();
+
+		let mut interface_parents: std::collections::BTreeMap<_, _> = Default::default();
+
+		for &Vlan { vlanif, r#if } in &opnconfig.vlans.0 {
+			interface_parents.insert(vlanif.to_owned(), r#if.to_owned());
+		}
+
+		for &Lagg { laggif, ref members } in &opnconfig.laggs.0 {
+			for &member in members {
+				interface_parents.insert(member.to_owned(), laggif.to_owned());
+			}
+		}
+
+		// Bridge members are logical interface names.
+		for &Bridged { bridgeif, ref members } in &opnconfig.bridges.0 {
+			for &member in members {
+				if let Some(Interface { r#if, .. }) = opnconfig.interfaces.0.get(member) {
+					interface_parents.insert((*r#if).to_owned(), bridgeif.to_owned());
+				}
+			}
+		}
+
+		let mut interface_addresses: std::collections::BTreeMap<_, InterfaceAddresses> =
+			opnconfig.interfaces.0.values()
+			.map(|&Interface { r#if, ipaddr, ipaddrv6, .. }| (r#if.to_owned(), InterfaceAddresses {
+				ipaddr: ipaddr.map(ToOwned::to_owned),
+				ipaddrv6: ipaddrv6.map(ToOwned::to_owned),
+				virtual_ips: vec![],
+			}))
+			.collect();
+
+		for &Vip { interface, subnet, mode } in &opnconfig.virtual_ips.0 {
+			if mode == "carp" {
+				continue;
+			}
+
+			// Virtual IPs are defined on logical interface names.
+			if let Some(Interface { r#if, .. }) = opnconfig.interfaces.0.get(interface) {
+				if let Ok(subnet) = subnet.parse() {
+					interface_addresses.entry((*r#if).to_owned()).or_default().virtual_ips.push(subnet);
+				}
+			}
+		}
+
+		let interface_descriptions =
+			opnconfig.interfaces.0.values()
+			.map(|&Interface { name, r#if, descr, enabled, .. }| (r#if.to_owned(), InterfaceDescription {
+				name: name.to_owned(),
+				descr: descr.map(ToOwned::to_owned),
+				enabled,
+			}))
+			.collect();
+
+		for GatewayItem { interface: gateway_interface, .. } in opnconfig.gateways.items.into_values() {
 			let _ = opnconfig.interfaces.0.remove(gateway_interface);
 		}
 
-		let other_interfaces = opnconfig.interfaces.0.into_values().map(ToOwned::to_owned).collect();
+		let other_interfaces = opnconfig.interfaces.0.into_values().map(|Interface { r#if, .. }| r#if.to_owned()).collect();
 
 		let result = OpnConfig {
 			gateway_interfaces,
 			other_interfaces,
 			gateways,
+			gateway_groups,
+			interface_descriptions,
+			interface_parents,
+			interface_addresses,
 		};
 
 		Ok(result)
@@ -51,6 +247,10 @@ impl OpnConfig {
 struct OpnSense<'input> {
 	interfaces: Interfaces<'input>,
 	gateways: Gateways<'input>,
+	vlans: Vlans<'input>,
+	laggs: Laggs<'input>,
+	bridges: Bridges<'input>,
+	virtual_ips: VirtualIps<'input>,
 }
 
 impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for OpnSense<'input> {
@@ -59,9 +259,17 @@ impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for OpnSense<'input> {
 	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
 		let interfaces_tag_name: roxmltree::ExpandedName<'_, '_> = "interfaces".into();
 		let gateways_tag_name: roxmltree::ExpandedName<'_, '_> = "gateways".into();
+		let vlans_tag_name: roxmltree::ExpandedName<'_, '_> = "vlans".into();
+		let laggs_tag_name: roxmltree::ExpandedName<'_, '_> = "laggs".into();
+		let bridges_tag_name: roxmltree::ExpandedName<'_, '_> = "bridges".into();
+		let virtualip_tag_name: roxmltree::ExpandedName<'_, '_> = "virtualip".into();
 
 		let mut interfaces = None;
 		let mut gateways = None;
+		let mut vlans = None;
+		let mut laggs = None;
+		let mut bridges = None;
+		let mut virtual_ips = None;
 
 		for child in node.children() {
 			let child_tag_name = child.tag_name();
@@ -71,20 +279,42 @@ impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for OpnSense<'input> {
 			else if child_tag_name == gateways_tag_name {
 				gateways = Some(child.try_into()?);
 			}
+			else if child_tag_name == vlans_tag_name {
+				vlans = Some(child.try_into()?);
+			}
+			else if child_tag_name == laggs_tag_name {
+				laggs = Some(child.try_into()?);
+			}
+			else if child_tag_name == bridges_tag_name {
+				bridges = Some(child.try_into()?);
+			}
+			else if child_tag_name == virtualip_tag_name {
+				virtual_ips = Some(child.try_into()?);
+			}
 		}
 
 		let interfaces = interfaces.ok_or("interfaces not found in config.xml")?;
 		let gateways = gateways.ok_or("gateways not found in config.xml")?;
 
+		// These sections only exist if the corresponding interfaces have been created.
+		let vlans = vlans.unwrap_or(Vlans(vec![]));
+		let laggs = laggs.unwrap_or(Laggs(vec![]));
+		let bridges = bridges.unwrap_or(Bridges(vec![]));
+		let virtual_ips = virtual_ips.unwrap_or(VirtualIps(vec![]));
+
 		Ok(OpnSense {
 			interfaces,
 			gateways,
+			vlans,
+			laggs,
+			bridges,
+			virtual_ips,
 		})
 	}
 }
 
 #[derive(Debug)]
-struct Interfaces<'input>(std::collections::BTreeMap<&'input str, &'input str>);
+struct Interfaces<'input>(std::collections::BTreeMap<&'input str, Interface<'input>>);
 
 impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Interfaces<'input> {
 	type Error = crate::Error;
@@ -94,15 +324,15 @@ impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Interfaces<'input> {
 			node.children()
 			.filter_map(|child|
 				if child.is_element() {
-					let Interface { name, r#if, internal_dynamic } = match child.try_into() {
+					let interface: Interface<'_> = match child.try_into() {
 						Ok(interface) => interface,
 						Err(err) => return Some(Err(err)),
 					};
-					if internal_dynamic {
+					if interface.internal_dynamic {
 						None
 					}
 					else {
-						Some(Ok((name, r#if)))
+						Some(Ok((interface.name, interface)))
 					}
 				}
 				else {
@@ -119,6 +349,10 @@ impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Interfaces<'input> {
 struct Interface<'input> {
 	name: &'input str,
 	r#if: &'input str,
+	descr: Option<&'input str>,
+	ipaddr: Option<&'input str>,
+	ipaddrv6: Option<&'input str>,
+	enabled: bool,
 	internal_dynamic: bool,
 }
 
@@ -127,6 +361,10 @@ impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Interface<'input> {
 
 	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
 		let if_tag_name: roxmltree::ExpandedName<'_, '_> = "if".into();
+		let descr_tag_name: roxmltree::ExpandedName<'_, '_> = "descr".into();
+		let enable_tag_name: roxmltree::ExpandedName<'_, '_> = "enable".into();
+		let ipaddr_tag_name: roxmltree::ExpandedName<'_, '_> = "ipaddr".into();
+		let ipaddrv6_tag_name: roxmltree::ExpandedName<'_, '_> = "ipaddrv6".into();
 		let internal_dynamic_tag_name: roxmltree::ExpandedName<'_, '_> = "internal_dynamic".into();
 
 		let name = node.tag_name().name();
@@ -134,43 +372,74 @@ impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Interface<'input> {
 		let r#if = node.children().find(|node| node.tag_name() == if_tag_name).ok_or("interfaces.*.if not found in config.xml")?;
 		let r#if = r#if.text().ok_or("interfaces.*.if is not a text node")?;
 
+		let descr = node.children().find(|node| node.tag_name() == descr_tag_name);
+		let descr = descr.and_then(|descr| descr.text()).map(str::trim).filter(|descr| !descr.is_empty());
+
+		let ipaddr = node.children().find(|node| node.tag_name() == ipaddr_tag_name);
+		let ipaddr = ipaddr.and_then(|ipaddr| ipaddr.text()).map(str::trim).filter(|ipaddr| !ipaddr.is_empty());
+
+		let ipaddrv6 = node.children().find(|node| node.tag_name() == ipaddrv6_tag_name);
+		let ipaddrv6 = ipaddrv6.and_then(|ipaddrv6| ipaddrv6.text()).map(str::trim).filter(|ipaddrv6| !ipaddrv6.is_empty());
+
+		// Enabled interfaces have `<enable>1</enable>`, or an empty `<enable />` in older configs. Disabled interfaces don't have the element at all.
+		let enable = node.children().find(|node| node.tag_name() == enable_tag_name);
+		let enabled = enable.is_some_and(|enable| enable.text().is_none_or(|enable| enable.trim() != "0"));
+
 		let internal_dynamic = node.children().find(|node| node.tag_name() == internal_dynamic_tag_name);
 		let internal_dynamic = internal_dynamic.and_then(|internal_dynamic| internal_dynamic.text()).map_or(false, |internal_dynamic| internal_dynamic == "1");
 
 		Ok(Interface {
 			name,
 			r#if,
+			descr,
+			ipaddr,
+			ipaddrv6,
+			enabled,
 			internal_dynamic,
 		})
 	}
 }
 
 #[derive(Debug)]
-struct Gateways<'input>(std::collections::BTreeMap<&'input str, &'input str>);
+struct Gateways<'input> {
+	items: std::collections::BTreeMap<&'input str, GatewayItem<'input>>,
+	groups: Vec<GatewayGroupItem<'input>>,
+}
 
 impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Gateways<'input> {
 	type Error = crate::Error;
 
 	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
 		let gateway_item_tag_name: roxmltree::ExpandedName<'_, '_> = "gateway_item".into();
+		let gateway_group_tag_name: roxmltree::ExpandedName<'_, '_> = "gateway_group".into();
 
-		let inner: Result<_, crate::Error> =
+		let items: Result<_, crate::Error> =
 			node.children()
 			.filter_map(|child|
 				if child.tag_name() == gateway_item_tag_name {
-					let GatewayItem { name, interface } = match child.try_into() {
+					let gateway: GatewayItem<'_> = match child.try_into() {
 						Ok(gateway) => gateway,
 						Err(err) => return Some(Err(err)),
 					};
-					Some(Ok((name, interface)))
+					Some(Ok((gateway.name, gateway)))
 				}
 				else {
 					None
 				})
 			.collect();
-		let inner = inner?;
+		let items = items?;
 
-		Ok(Gateways(inner))
+		let groups: Result<_, crate::Error> =
+			node.children()
+			.filter(|child| child.tag_name() == gateway_group_tag_name)
+			.map(TryInto::try_into)
+			.collect();
+		let groups = groups?;
+
+		Ok(Gateways {
+			items,
+			groups,
+		})
 	}
 }
 
@@ -178,6 +447,14 @@ impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Gateways<'input> {
 struct GatewayItem<'input> {
 	name: &'input str,
 	interface: &'input str,
+	gateway: Option<&'input str>,
+	ipprotocol: Option<&'input str>,
+	monitor: Option<&'input str>,
+	defaultgw: bool,
+	latencylow: Option<&'input str>,
+	latencyhigh: Option<&'input str>,
+	losslow: Option<&'input str>,
+	losshigh: Option<&'input str>,
 }
 
 impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for GatewayItem<'input> {
@@ -193,9 +470,254 @@ impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for GatewayItem<'input> {
 		let interface = node.children().find(|node| node.tag_name() == interface_tag_name).ok_or("gateways.gateway_item.interface not found in config.xml")?;
 		let interface = interface.text().ok_or("gateways.gateway_item.interface is not a text node")?;
 
+		// These are all optional, and are empty elements when not set.
+		let optional_text = |tag_name: &str| {
+			let tag_name: roxmltree::ExpandedName<'_, '_> = tag_name.into();
+			let node = node.children().find(|node| node.tag_name() == tag_name)?;
+			node.text().map(str::trim).filter(|text| !text.is_empty())
+		};
+
 		Ok(GatewayItem {
 			name,
 			interface,
+			gateway: optional_text("gateway"),
+			ipprotocol: optional_text("ipprotocol"),
+			monitor: optional_text("monitor"),
+			defaultgw: optional_text("defaultgw") == Some("1"),
+			latencylow: optional_text("latencylow"),
+			latencyhigh: optional_text("latencyhigh"),
+			losslow: optional_text("losslow"),
+			losshigh: optional_text("losshigh"),
+		})
+	}
+}
+
+#[derive(Debug)]
+struct GatewayGroupItem<'input> {
+	name: &'input str,
+	items: Vec<&'input str>,
+	trigger: Option<&'input str>,
+}
+
+impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for GatewayGroupItem<'input> {
+	type Error = crate::Error;
+
+	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
+		let name_tag_name: roxmltree::ExpandedName<'_, '_> = "name".into();
+		let item_tag_name: roxmltree::ExpandedName<'_, '_> = "item".into();
+		let trigger_tag_name: roxmltree::ExpandedName<'_, '_> = "trigger".into();
+
+		let name = node.children().find(|node| node.tag_name() == name_tag_name).ok_or("gateways.gateway_group.name not found in config.xml")?;
+		let name = name.text().ok_or("gateways.gateway_group.name is not a text node")?;
+
+		let items =
+			node.children()
+			.filter(|node| node.tag_name() == item_tag_name)
+			.filter_map(|node| node.text())
+			.collect();
+
+		let trigger = node.children().find(|node| node.tag_name() == trigger_tag_name).and_then(|node| node.text());
+
+		Ok(GatewayGroupItem {
+			name,
+			items,
+			trigger,
+		})
+	}
+}
+
+#[derive(Debug)]
+struct Vlans<'input>(Vec<Vlan<'input>>);
+
+impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Vlans<'input> {
+	type Error = crate::Error;
+
+	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
+		let vlan_tag_name: roxmltree::ExpandedName<'_, '_> = "vlan".into();
+
+		let inner: Result<_, crate::Error> =
+			node.children()
+			.filter(|child| child.tag_name() == vlan_tag_name)
+			.map(TryInto::try_into)
+			.collect();
+		let inner = inner?;
+
+		Ok(Vlans(inner))
+	}
+}
+
+#[derive(Debug)]
+struct Vlan<'input> {
+	vlanif: &'input str,
+	r#if: &'input str,
+}
+
+impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Vlan<'input> {
+	type Error = crate::Error;
+
+	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
+		let vlanif_tag_name: roxmltree::ExpandedName<'_, '_> = "vlanif".into();
+		let if_tag_name: roxmltree::ExpandedName<'_, '_> = "if".into();
+
+		let vlanif = node.children().find(|node| node.tag_name() == vlanif_tag_name).ok_or("vlans.vlan.vlanif not found in config.xml")?;
+		let vlanif = vlanif.text().ok_or("vlans.vlan.vlanif is not a text node")?;
+
+		let r#if = node.children().find(|node| node.tag_name() == if_tag_name).ok_or("vlans.vlan.if not found in config.xml")?;
+		let r#if = r#if.text().ok_or("vlans.vlan.if is not a text node")?;
+
+		Ok(Vlan {
+			vlanif,
+			r#if,
+		})
+	}
+}
+
+#[derive(Debug)]
+struct Laggs<'input>(Vec<Lagg<'input>>);
+
+impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Laggs<'input> {
+	type Error = crate::Error;
+
+	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
+		let lagg_tag_name: roxmltree::ExpandedName<'_, '_> = "lagg".into();
+
+		let inner: Result<_, crate::Error> =
+			node.children()
+			.filter(|child| child.tag_name() == lagg_tag_name)
+			.map(TryInto::try_into)
+			.collect();
+		let inner = inner?;
+
+		Ok(Laggs(inner))
+	}
+}
+
+#[derive(Debug)]
+struct Lagg<'input> {
+	laggif: &'input str,
+	members: Vec<&'input str>,
+}
+
+impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Lagg<'input> {
+	type Error = crate::Error;
+
+	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
+		let laggif_tag_name: roxmltree::ExpandedName<'_, '_> = "laggif".into();
+		let members_tag_name: roxmltree::ExpandedName<'_, '_> = "members".into();
+
+		let laggif = node.children().find(|node| node.tag_name() == laggif_tag_name).ok_or("laggs.lagg.laggif not found in config.xml")?;
+		let laggif = laggif.text().ok_or("laggs.lagg.laggif is not a text node")?;
+
+		// Comma-separated list of device names
+		let members = node.children().find(|node| node.tag_name() == members_tag_name);
+		let members = members.and_then(|members| members.text()).unwrap_or_default();
+		let members = members.split(',').map(str::trim).filter(|member| !member.is_empty()).collect();
+
+		Ok(Lagg {
+			laggif,
+			members,
+		})
+	}
+}
+
+#[derive(Debug)]
+struct Bridges<'input>(Vec<Bridged<'input>>);
+
+impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Bridges<'input> {
+	type Error = crate::Error;
+
+	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
+		let bridged_tag_name: roxmltree::ExpandedName<'_, '_> = "bridged".into();
+
+		let inner: Result<_, crate::Error> =
+			node.children()
+			.filter(|child| child.tag_name() == bridged_tag_name)
+			.map(TryInto::try_into)
+			.collect();
+		let inner = inner?;
+
+		Ok(Bridges(inner))
+	}
+}
+
+#[derive(Debug)]
+struct Bridged<'input> {
+	bridgeif: &'input str,
+	members: Vec<&'input str>,
+}
+
+impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Bridged<'input> {
+	type Error = crate::Error;
+
+	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
+		let bridgeif_tag_name: roxmltree::ExpandedName<'_, '_> = "bridgeif".into();
+		let members_tag_name: roxmltree::ExpandedName<'_, '_> = "members".into();
+
+		let bridgeif = node.children().find(|node| node.tag_name() == bridgeif_tag_name).ok_or("bridges.bridged.bridgeif not found in config.xml")?;
+		let bridgeif = bridgeif.text().ok_or("bridges.bridged.bridgeif is not a text node")?;
+
+		// Comma-separated list of logical interface names
+		let members = node.children().find(|node| node.tag_name() == members_tag_name);
+		let members = members.and_then(|members| members.text()).unwrap_or_default();
+		let members = members.split(',').map(str::trim).filter(|member| !member.is_empty()).collect();
+
+		Ok(Bridged {
+			bridgeif,
+			members,
+		})
+	}
+}
+
+#[derive(Debug)]
+struct VirtualIps<'input>(Vec<Vip<'input>>);
+
+impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for VirtualIps<'input> {
+	type Error = crate::Error;
+
+	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
+		let vip_tag_name: roxmltree::ExpandedName<'_, '_> = "vip".into();
+
+		let inner: Result<_, crate::Error> =
+			node.children()
+			.filter(|child| child.tag_name() == vip_tag_name)
+			.map(TryInto::try_into)
+			.collect();
+		let inner = inner?;
+
+		Ok(VirtualIps(inner))
+	}
+}
+
+#[derive(Debug)]
+struct Vip<'input> {
+	interface: &'input str,
+	subnet: &'input str,
+
+	// `carp`, `ipalias` or `proxyarp`
+	mode: &'input str,
+}
+
+impl<'input> TryFrom<roxmltree::Node<'input, 'input>> for Vip<'input> {
+	type Error = crate::Error;
+
+	fn try_from(node: roxmltree::Node<'input, 'input>) -> Result<Self, Self::Error> {
+		let interface_tag_name: roxmltree::ExpandedName<'_, '_> = "interface".into();
+		let subnet_tag_name: roxmltree::ExpandedName<'_, '_> = "subnet".into();
+		let mode_tag_name: roxmltree::ExpandedName<'_, '_> = "mode".into();
+
+		let interface = node.children().find(|node| node.tag_name() == interface_tag_name).ok_or("virtualip.vip.interface not found in config.xml")?;
+		let interface = interface.text().ok_or("virtualip.vip.interface is not a text node")?;
+
+		let subnet = node.children().find(|node| node.tag_name() == subnet_tag_name).ok_or("virtualip.vip.subnet not found in config.xml")?;
+		let subnet = subnet.text().ok_or("virtualip.vip.subnet is not a text node")?;
+
+		let mode = node.children().find(|node| node.tag_name() == mode_tag_name).ok_or("virtualip.vip.mode not found in config.xml")?;
+		let mode = mode.text().ok_or("virtualip.vip.mode is not a text node")?;
+
+		Ok(Vip {
+			interface,
+			subnet,
+			mode,
 		})
 	}
 }
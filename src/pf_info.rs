@@ -0,0 +1,49 @@
+pub(crate) type Counter = fn(&crate::ssh_exec::pfctl_s_info::Counters) -> u64;
+
+#[derive(Debug)]
+pub(crate) struct PfInfo {
+	pub(crate) status: String,
+	pub(crate) debug: String,
+	pub(crate) states_used: u64,
+
+	counters_previous: Option<crate::ssh_exec::pfctl_s_info::Counters>,
+	counters: Option<crate::ssh_exec::pfctl_s_info::Counters>,
+}
+
+impl PfInfo {
+	pub(crate) fn new() -> Self {
+		PfInfo {
+			status: String::new(),
+			debug: String::new(),
+			states_used: 0,
+
+			counters_previous: None,
+			counters: None,
+		}
+	}
+
+	pub(crate) fn update(&mut self, session: &ssh2::Session) -> Result<(), crate::Error> {
+		let crate::ssh_exec::pfctl_s_info::Info { status, debug, current_entries, counters } = crate::ssh_exec::pfctl_s_info::run(session)?;
+		self.status = status;
+		self.debug = debug;
+		self.states_used = current_entries;
+		self.counters_previous = self.counters.replace(counters);
+		Ok(())
+	}
+
+	pub(crate) fn is_enabled(&self) -> bool {
+		self.status == "Enabled"
+	}
+
+	// Returns None for the first sample, and if the counters were reset with `pfctl -F info` since the previous sample.
+	pub(crate) fn rate(
+		&self,
+		counter: Counter,
+		time_since_previous: std::time::Duration,
+	) -> Option<f32> {
+		let previous = counter(self.counters_previous.as_ref()?);
+		let current = counter(self.counters.as_ref()?);
+		let diff = current.checked_sub(previous)?;
+		Some(diff as f32 / time_since_previous.as_secs_f32())
+	}
+}
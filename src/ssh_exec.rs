@@ -1,9 +1,11 @@
 pub(crate) mod batched_sysctls_1 {
-	pub(crate) fn run(session: &ssh2::Session) -> Result<(crate::boot_time::BootTime, crate::memory::Memory), crate::Error> {
-		let mut reader = super::exec(session, "/sbin/sysctl -b kern.boottime hw.physmem vm.stats.vm.v_page_count")?;
+	pub(crate) fn run(session: &ssh2::Session) -> Result<(crate::boot_time::BootTime, crate::memory::Memory, crate::cpu_info::CpuInfo), crate::Error> {
+		// hw.model is variable length, so it has to be at the end
+		let mut reader = super::exec(session, "/sbin/sysctl -b kern.boottime vm.stats.vm.v_page_count hw.pagesize hw.ncpu hw.model")?;
 		let boot_time = crate::Parse::parse(&mut reader)?;
 		let memory = crate::Parse::parse(&mut reader)?;
-		Ok((boot_time, memory))
+		let cpu_info = crate::Parse::parse(&mut reader)?;
+		Ok((boot_time, memory, cpu_info))
 	}
 }
 
@@ -14,15 +16,29 @@ pub(crate) mod batched_sysctls_2 {
 	}
 
 	impl Exec {
-		pub(crate) fn new(temperature_sysctls: &[crate::temperature_sysctl::TemperatureSysctl]) -> Self {
-			let mut command = "/sbin/sysctl -b vm.stats.vm.v_inactive_count vm.stats.vm.v_cache_count vm.stats.vm.v_free_count".to_owned();
+		pub(crate) fn new(
+			memory: &crate::memory::Memory,
+			temperature_sysctls: &[crate::temperature_sysctl::TemperatureSysctl],
+			cpu_frequency_sysctls: &[crate::cpu_frequency_sysctl::CpuFrequencySysctl],
+		) -> Self {
+			use std::fmt::Write;
 
-			for temperature_sysctl in temperature_sysctls {
-				use std::fmt::Write;
+			let mut command = "/sbin/sysctl -b vm.stats.vm.v_active_count vm.stats.vm.v_inactive_count vm.stats.vm.v_laundry_count vm.stats.vm.v_wire_count vm.stats.vm.v_free_count".to_owned();
+
+			if memory.arc.is_some() {
+				command.push_str(" kstat.zfs.misc.arcstats.size kstat.zfs.misc.arcstats.c_min kstat.zfs.misc.arcstats.c_max");
+			}
 
+			command.push_str(" vm.loadavg");
+
+			for temperature_sysctl in temperature_sysctls {
 				write!(command, " '{}'", temperature_sysctl.name).unwrap();
 			}
 
+			for cpu_frequency_sysctl in cpu_frequency_sysctls {
+				write!(command, " '{}'", cpu_frequency_sysctl.name).unwrap();
+			}
+
 			// kern.cp_time is variable length, so it has to be at the end
 			command.push_str(" kern.cp_time");
 
@@ -35,17 +51,25 @@ pub(crate) mod batched_sysctls_2 {
 			&self,
 			cpu: &mut crate::cpu::Cpu,
 			memory: &mut crate::memory::Memory,
+			load_average: &mut crate::load_average::LoadAverage,
 			temperature_sysctls: &mut [crate::temperature_sysctl::TemperatureSysctl],
+			cpu_frequency_sysctls: &mut [crate::cpu_frequency_sysctl::CpuFrequencySysctl],
 			session: &ssh2::Session,
 		) -> Result<(), crate::Error> {
 			let mut reader = super::exec(session, &self.command)?;
 
 			memory.update(&mut reader)?;
 
+			*load_average = crate::Parse::parse(&mut reader)?;
+
 			for temperature_sysctl in temperature_sysctls {
 				temperature_sysctl.update(&mut reader)?;
 			}
 
+			for cpu_frequency_sysctl in cpu_frequency_sysctls {
+				cpu_frequency_sysctl.update(&mut reader)?;
+			}
+
 			cpu.update(&mut reader)?;
 
 			Ok(())
@@ -53,6 +77,31 @@ pub(crate) mod batched_sysctls_2 {
 	}
 }
 
+pub(crate) mod camcontrol_devlist_v {
+	// Returns the controller of the given CAM peripheral, like `nvme0` for `nda0`, or None if the peripheral was not found.
+	pub(crate) fn get_controller(peripheral: &str, session: &ssh2::Session) -> Result<Option<String>, crate::Error> {
+		// scbus0 on nvme0 bus 0:
+		// <Samsung SSD 970 EVO Plus 1TB 2B2QEXM7>  at scbus0 target 0 lun 1 (pass0,nda0)
+		// <>                                 at scbus0 target -1 lun ffffffff (xpt0)
+		let mut controller = None;
+
+		for line in super::read_lines(super::exec(session, "/sbin/camcontrol devlist -v")) {
+			let line = line?;
+
+			if line.starts_with("scbus") {
+				controller = line.split(' ').nth(2).map(ToOwned::to_owned);
+			}
+			else if let Some((_, peripherals)) = line.rsplit_once('(') {
+				if peripherals.trim_end_matches(')').split(',').any(|name| name == peripheral) {
+					return Ok(controller);
+				}
+			}
+		}
+
+		Ok(None)
+	}
+}
+
 pub(crate) mod clog_filter_log {
 	#[derive(Debug, serde::Deserialize)]
 	pub(crate) struct Log {
@@ -218,6 +267,18 @@ pub(crate) mod clog_filter_log {
 	}
 }
 
+pub(crate) mod date {
+	// The router's local date, as (year, month, day)
+	pub(crate) fn get_date(session: &ssh2::Session) -> Result<(u32, u32, u32), crate::Error> {
+		let date = super::read_line(super::exec(session, "/bin/date '+%Y %m %d'"))?;
+		let mut parts = date.split_whitespace().map(str::parse);
+		let year = parts.next().ok_or("date output is malformed")??;
+		let month = parts.next().ok_or("date output is malformed")??;
+		let day = parts.next().ok_or("date output is malformed")??;
+		Ok((year, month, day))
+	}
+}
+
 pub(crate) mod df {
 	#[derive(Debug, serde::Deserialize)]
 	struct Output {
@@ -232,26 +293,30 @@ pub(crate) mod df {
 
 	#[derive(Debug, serde::Deserialize)]
 	pub(crate) struct Filesystem {
+		#[serde(rename = "available-blocks")]
+		pub(crate) available_blocks: u64,
+		#[serde(rename = "free-inodes")]
+		pub(crate) free_inodes: u64,
 		#[serde(rename = "mounted-on")]
 		pub(crate) mounted_on: String,
+		pub(crate) name: String,
 		#[serde(rename = "total-blocks")]
 		pub(crate) total_blocks: u64,
 		#[serde(rename = "type")]
 		pub(crate) r#type: String,
 		#[serde(rename = "used-blocks")]
 		pub(crate) used_blocks: u64,
+		#[serde(rename = "used-inodes")]
+		pub(crate) used_inodes: u64,
 	}
 
 	pub(crate) fn get_filesystems(session: &ssh2::Session) -> Result<Vec<Filesystem>, crate::Error> {
 		let Output { storage_system_information: StorageSystemInformation { mut filesystem } } =
-			super::read_json(super::exec(session, "/bin/df -aT --libxo json")?)?;
-		filesystem.retain(|filesystem| matches!(filesystem.r#type.trim_end(), "tmpfs" | "ufs" | "zfs"));
-		filesystem.sort_by(|fs1, fs2|
-			fs1.mounted_on.cmp(&fs2.mounted_on)
-			.then_with(|| fs1.r#type.cmp(&fs2.r#type))
-			.then_with(|| fs1.total_blocks.cmp(&fs2.total_blocks))
-			.then_with(|| fs1.used_blocks.cmp(&fs2.used_blocks))
-		);
+			super::read_json(super::exec(session, "/bin/df -aiT --libxo json")?)?;
+		for filesystem in &mut filesystem {
+			filesystem.name.truncate(filesystem.name.trim_end().len());
+			filesystem.r#type.truncate(filesystem.r#type.trim_end().len());
+		}
 		Ok(filesystem)
 	}
 }
@@ -295,42 +360,395 @@ pub(crate) mod dpinger {
 }
 
 pub(crate) mod ifconfig {
+	pub(crate) fn run(session: &ssh2::Session) -> Result<std::collections::BTreeMap<String, Ifconfig>, crate::Error> {
+		let mut result: std::collections::BTreeMap<_, Ifconfig> = Default::default();
+		let mut current = None;
+
+		// Each interface starts with an unindented line, followed by indented lines with its details.
+		for line in super::read_lines(super::exec(session, "/sbin/ifconfig -a")) {
+			let line = line?;
+
+			if !line.starts_with(char::is_whitespace) {
+				let Some((name, _)) = line.split_once(": ") else { continue; };
+				current = Some(result.entry(name.to_owned()).or_default());
+			}
+
+			if let Some(current) = &mut current {
+				current.parse_line(&line);
+			}
+		}
+
+		Ok(result)
+	}
+
+	#[derive(Debug, Default)]
+	pub(crate) struct Ifconfig {
+		pub(crate) flags: Vec<String>,
+		pub(crate) mtu: Option<u32>,
+		pub(crate) mac: Option<String>,
+		pub(crate) media: Option<Media>,
+		pub(crate) addresses: Vec<Address>,
+		pub(crate) groups: Vec<String>,
+		pub(crate) vlan_parent: Option<String>,
+		pub(crate) lagg_proto: Option<String>,
+		pub(crate) lagg_ports: Vec<LaggPort>,
+		pub(crate) bridge_members: Vec<String>,
+		status: Option<String>,
+	}
+
 	#[derive(Debug)]
-	pub(crate) struct Exec {
-		command: String,
+	pub(crate) struct Address {
+		pub(crate) ip: std::net::IpAddr,
+		pub(crate) prefix_len: u8,
+
+		// The CARP virtual host ID, for CARP addresses
+		pub(crate) vhid: Option<u8>,
+
+		// Like `autoconf`, `deprecated` or `tentative`
+		pub(crate) flags: Vec<String>,
 	}
 
-	impl Exec {
-		pub(crate) fn new(name: &str) -> Self {
-			let command = format!("/sbin/ifconfig '{name}'");
-			Exec {
-				command,
+	#[derive(Debug)]
+	pub(crate) struct LaggPort {
+		pub(crate) name: String,
+
+		// Like `ACTIVE,COLLECTING,DISTRIBUTING` for LACP
+		pub(crate) flags: Vec<String>,
+	}
+
+	#[derive(Debug)]
+	pub(crate) struct Media {
+		// The active media, like `1000baseT full-duplex`
+		pub(crate) description: String,
+
+		// Bits per second
+		pub(crate) speed: Option<f32>,
+	}
+
+	impl Ifconfig {
+		// igb0: flags=8843<UP,BROADCAST,RUNNING,SIMPLEX,MULTICAST> metric 0 mtu 1500
+		// 	ether 00:11:22:33:44:55
+		// 	inet 192.168.1.1 netmask 0xffffff00 broadcast 192.168.1.255
+		// 	inet 192.168.1.254 netmask 0xffffff00 broadcast 192.168.1.255 vhid 1
+		// 	inet6 fe80::1%igb0 prefixlen 64 scopeid 0x1
+		// 	inet6 2001:db8::1 prefixlen 64 autoconf
+		// 	...
+		// 	media: Ethernet autoselect (1000baseT <full-duplex>)
+		// 	status: active
+		// 	vlan: 10 vlanproto: 802.1q vlanpcp: 0 parent interface: igb0
+		// 	laggproto lacp lagghash l2,l3,l4
+		// 	laggport: igb0 flags=1c<ACTIVE,COLLECTING,DISTRIBUTING>
+		// 	member: igb1 flags=143<LEARNING,DISCOVER,AUTOEDGE,AUTOPTP>
+		// 	groups: vlan
+		fn parse_line(&mut self, line: &str) {
+			let trimmed_line = line.trim_start();
+
+			if !line.starts_with(char::is_whitespace) {
+				if let Some((_, flags)) = line.split_once(" flags=") {
+					self.flags = parse_flags(flags);
+
+					let mut parts = flags.split_whitespace();
+					while let Some(part) = parts.next() {
+						if part == "mtu" {
+							self.mtu = parts.next().and_then(|mtu| mtu.parse().ok());
+						}
+					}
+				}
+			}
+			else if let Some(lagg_port) = trimmed_line.strip_prefix("laggport:") {
+				let (name, flags) = lagg_port.trim().split_once(' ').unwrap_or((lagg_port.trim(), ""));
+				self.lagg_ports.push(LaggPort {
+					name: name.to_owned(),
+					flags: flags.split_once("flags=").map(|(_, flags)| parse_flags(flags)).unwrap_or_default(),
+				});
+			}
+			else if let Some(lagg_proto) = trimmed_line.strip_prefix("laggproto ") {
+				self.lagg_proto = lagg_proto.split_whitespace().next().map(ToOwned::to_owned);
+			}
+			else if let Some(member) = trimmed_line.strip_prefix("member:") {
+				if let Some(member) = member.split_whitespace().next() {
+					self.bridge_members.push(member.to_owned());
+				}
+			}
+			else if let Some(vlan) = trimmed_line.strip_prefix("vlan:") {
+				if let Some((_, parent)) = vlan.split_once("parent interface:") {
+					self.vlan_parent = Some(parent.trim().to_owned());
+				}
+			}
+			else if let Some(groups) = trimmed_line.strip_prefix("groups:") {
+				self.groups = groups.split_whitespace().map(ToOwned::to_owned).collect();
+			}
+			else if let Some(mac) = trimmed_line.strip_prefix("ether ") {
+				self.mac = Some(mac.trim().to_owned());
+			}
+			else if let Some(address) = trimmed_line.strip_prefix("inet ").or_else(|| trimmed_line.strip_prefix("inet6 ")) {
+				if let Some(address) = Address::parse(address) {
+					self.addresses.push(address);
+				}
+			}
+			else if let Some(media) = trimmed_line.strip_prefix("media:") {
+				self.media = Some(Media::parse(media));
+			}
+			else if let Some(status) = trimmed_line.strip_prefix("status:") {
+				self.status = Some(status.trim().to_owned());
 			}
 		}
 
-		pub(crate) fn run(&self, session: &ssh2::Session) -> Result<Option<String>, crate::Error> {
-			let status =
-				super::read_lines(super::exec(session, &self.command))
-				.find_map(|line| match line {
-					Ok(line) => {
-						let index = line.find("status:")?;
-						let value = line[(index + "status:".len())..].trim().to_owned();
-						Some(Ok(value))
+		pub(crate) fn status(&self) -> Status {
+			// Interfaces without a status line, like tunnels, are considered active as long as they're up.
+			match self.status.as_deref() {
+				_ if !self.flags.iter().any(|flag| flag == "UP") => Status::AdminDown,
+				None | Some("active") => Status::Active,
+				Some("no carrier") => Status::NoCarrier,
+				Some(status) => Status::Other(status.to_owned()),
+			}
+		}
+	}
+
+	impl Address {
+		fn parse(s: &str) -> Option<Self> {
+			let mut parts = s.split_whitespace();
+
+			// Link-local IPv6 addresses have a zone suffix.
+			let ip = parts.next()?;
+			let ip = ip.split_once('%').map_or(ip, |(ip, _)| ip);
+			let ip: std::net::IpAddr = ip.parse().ok()?;
+
+			let mut prefix_len = match ip {
+				std::net::IpAddr::V4(_) => 32,
+				std::net::IpAddr::V6(_) => 128,
+			};
+			let mut vhid = None;
+			let mut flags = vec![];
+
+			while let Some(part) = parts.next() {
+				match part {
+					"netmask" => {
+						let netmask = parts.next()?;
+						let netmask = u32::from_str_radix(netmask.strip_prefix("0x").unwrap_or(netmask), 16).ok()?;
+						prefix_len = netmask.count_ones().try_into().ok()?;
 					},
-					Err(err) => Some(Err(err)),
-				})
-				.transpose()?;
+					"prefixlen" => prefix_len = parts.next()?.parse().ok()?,
+					"vhid" => vhid = parts.next()?.parse().ok(),
+					"broadcast" | "scopeid" | "pltime" | "vltime" => {
+						let _ = parts.next();
+					},
+					flag => flags.push(flag.to_owned()),
+				}
+			}
+
+			Some(Address {
+				ip,
+				prefix_len,
+				vhid,
+				flags,
+			})
+		}
+	}
 
-			if status.as_ref().map(AsRef::as_ref) == Some("active") {
-				Ok(None)
+	// 8843<UP,BROADCAST,RUNNING,SIMPLEX,MULTICAST> metric 0 mtu 1500
+	fn parse_flags(s: &str) -> Vec<String> {
+		let Some((_, flags)) = s.split_once('<') else { return vec![]; };
+		let flags = flags.split_once('>').map_or(flags, |(flags, _)| flags);
+		flags.split(',').filter(|flag| !flag.is_empty()).map(ToOwned::to_owned).collect()
+	}
+
+	impl Media {
+		fn parse(s: &str) -> Self {
+			// Ethernet autoselect (1000baseT <full-duplex>)
+			// Ethernet 10Gbase-SR <full-duplex,rxpause,txpause>
+			// Ethernet autoselect
+			let s = s.trim();
+			let active =
+				if let Some((_, active)) = s.split_once('(') {
+					active.split_once(')').map_or(active, |(active, _)| active)
+				}
+				else {
+					s.split_once(' ').map_or(s, |(_, active)| active)
+				};
+
+			let description: String = active.chars().filter(|&c| c != '<' && c != '>').collect();
+
+			// 10baseT, 100baseTX, 1000baseT, 2500Base-T, 10Gbase-SR, 25GBase-CR
+			let speed = active.split_once(|c: char| c.eq_ignore_ascii_case(&'b')).and_then(|(speed, _)| {
+				let (speed, multiplier) = speed.strip_suffix('G').map_or((speed, 1_000_000.), |speed| (speed, 1_000_000_000.));
+				let speed: f32 = speed.parse().ok()?;
+				Some(speed * multiplier)
+			});
+
+			Media {
+				description,
+				speed,
 			}
-			else {
-				Ok(status)
+		}
+	}
+
+	#[derive(Debug)]
+	pub(crate) enum Status {
+		Active,
+
+		// The interface does not have the UP flag.
+		AdminDown,
+
+		NoCarrier,
+
+		// The interface does not exist, such as a PPPoE or VPN interface whose connection is down.
+		NotPresent,
+
+		Other(String),
+	}
+
+	impl std::fmt::Display for Status {
+		fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+			match self {
+				Status::Active => f.pad("active"),
+				Status::AdminDown => f.pad("down"),
+				Status::NoCarrier => f.pad("no carrier"),
+				Status::NotPresent => f.pad("not present"),
+				Status::Other(status) => f.pad(status),
 			}
 		}
 	}
 }
 
+pub(crate) mod ipmitool_sdr_elist {
+	#[derive(Debug)]
+	pub(crate) struct Sdr {
+		pub(crate) name: String,
+
+		// One of `ok`, `ns` (no reading), `nc` (non-critical), `cr` (critical) or `nr` (non-recoverable).
+		pub(crate) status: String,
+
+		pub(crate) description: String,
+	}
+
+	pub(crate) fn run(session: &ssh2::Session) -> impl Iterator<Item = Result<Sdr, crate::Error>> {
+		// name | sensor ID | status | entity ID | reading or decoded states
+		//
+		// CPU Temp         | 01h | ok  |  3.1 | 45 degrees C
+		// PS1 Status       | C8h | ok  | 10.1 | Presence detected, Power Supply AC lost
+		super::read_lines(super::exec(session, "/usr/local/bin/ipmitool sdr elist"))
+			.map(|line| -> Result<_, crate::Error> {
+				let line = line?;
+
+				let mut line_parts = line.split('|').map(str::trim);
+
+				let name = line_parts.next().ok_or("ipmitool sdr output is malformed")?;
+				let _sensor_id = line_parts.next();
+				let status = line_parts.next().ok_or("ipmitool sdr output is malformed")?;
+				let _entity_id = line_parts.next();
+				let description = line_parts.next().unwrap_or_default();
+
+				Ok(Sdr {
+					name: name.to_owned(),
+					status: status.to_owned(),
+					description: description.to_owned(),
+				})
+			})
+	}
+}
+
+pub(crate) mod ipmitool_sensor {
+	#[derive(Debug)]
+	pub(crate) struct Sensor {
+		pub(crate) name: String,
+
+		// None if the sensor has no reading, or if it's a discrete sensor.
+		pub(crate) value: Option<f32>,
+
+		pub(crate) unit: String,
+
+		// For threshold sensors, one of `ok`, `nc` (non-critical), `cr` (critical), `nr` (non-recoverable) or `na`.
+		// For discrete sensors, the raw state bits, until it's replaced by the status from `ipmitool sdr`.
+		pub(crate) status: String,
+
+		// For discrete sensors, the decoded states from `ipmitool sdr`, like `Presence detected, Power Supply AC lost`.
+		pub(crate) description: Option<String>,
+
+		pub(crate) lower_critical: Option<f32>,
+		pub(crate) upper_critical: Option<f32>,
+	}
+
+	pub(crate) fn run(session: &ssh2::Session) -> impl Iterator<Item = Result<Sensor, crate::Error>> {
+		// name | value | unit | status | lower non-recoverable | lower critical | lower non-critical | upper non-critical | upper critical | upper non-recoverable
+		//
+		// CPU Temp         | 45.000     | degrees C  | ok    | 0.000     | 0.000     | 0.000     | 95.000    | 100.000   | 100.000
+		// FAN1             | 3200.000   | RPM        | ok    | 300.000   | 500.000   | 700.000   | 25300.000 | 25400.000 | 25500.000
+		// PS1 Status       | 0x1        | discrete   | 0x0100| na        | na        | na        | na        | na        | na
+		super::read_lines(super::exec(session, "/usr/local/bin/ipmitool sensor"))
+			.map(|line| -> Result<_, crate::Error> {
+				let line = line?;
+
+				let mut line_parts = line.split('|').map(str::trim);
+
+				let name = line_parts.next().ok_or("ipmitool sensor output is malformed")?;
+				let value = line_parts.next().ok_or("ipmitool sensor output is malformed")?;
+				let unit = line_parts.next().ok_or("ipmitool sensor output is malformed")?;
+				let status = line_parts.next().ok_or("ipmitool sensor output is malformed")?;
+				let _lower_non_recoverable = line_parts.next();
+				let lower_critical = line_parts.next();
+				let _lower_non_critical = line_parts.next();
+				let _upper_non_critical = line_parts.next();
+				let upper_critical = line_parts.next();
+
+				// Discrete sensors have a hex value and no thresholds, and `na` means the sensor has no reading.
+				let parse_reading = |s: Option<&str>| s.and_then(|s| s.parse().ok());
+
+				Ok(Sensor {
+					name: name.to_owned(),
+					value: if unit == "discrete" { None } else { parse_reading(Some(value)) },
+					unit: unit.to_owned(),
+					status: status.to_owned(),
+					description: None,
+					lower_critical: parse_reading(lower_critical),
+					upper_critical: parse_reading(upper_critical),
+				})
+			})
+	}
+}
+
+pub(crate) mod iostat_ix {
+	// All values are totals since boot.
+	#[derive(Clone, Copy, Debug, Default)]
+	pub(crate) struct Statistics {
+		pub(crate) reads: f64,
+		pub(crate) writes: f64,
+		pub(crate) kib_read: f64,
+		pub(crate) kib_written: f64,
+		pub(crate) service_time_secs: f64,
+		pub(crate) busy_time_secs: f64,
+	}
+
+	pub(crate) fn run(session: &ssh2::Session) -> impl Iterator<Item = Result<(String, Statistics), crate::Error>> {
+		//                         extended device statistics
+		// device       r/i         w/i         kr/i         kw/i qlen   tsvc_t/i      sb/i
+		// ada0     123456.0    654321.0    1234567.0    7654321.0    0      123.4     456.7
+		super::read_lines(super::exec(session, "/usr/sbin/iostat -Ix -d"))
+			.filter_map(|line| line.and_then(|line| parse_line(&line)).transpose())
+	}
+
+	fn parse_line(line: &str) -> Result<Option<(String, Statistics)>, crate::Error> {
+		let mut line_parts = line.split_whitespace();
+
+		let (Some(name), Some(reads), Some(writes), Some(kib_read), Some(kib_written), Some(_queue_length), Some(service_time_secs), Some(busy_time_secs)) = (
+			line_parts.next(), line_parts.next(), line_parts.next(), line_parts.next(),
+			line_parts.next(), line_parts.next(), line_parts.next(), line_parts.next(),
+		) else { return Ok(None); };
+
+		// Skip the header lines
+		let Ok(reads) = reads.parse() else { return Ok(None); };
+
+		Ok(Some((name.to_owned(), Statistics {
+			reads,
+			writes: writes.parse()?,
+			kib_read: kib_read.parse()?,
+			kib_written: kib_written.parse()?,
+			service_time_secs: service_time_secs.parse()?,
+			busy_time_secs: busy_time_secs.parse()?,
+		})))
+	}
+}
+
 pub(crate) mod netstat_bin {
 	#[derive(Debug, serde::Deserialize)]
 	struct Output {
@@ -342,15 +760,26 @@ pub(crate) mod netstat_bin {
 		interface: Vec<Interface>,
 	}
 
+	// The error and drop counters are only present for the link-level entry of each interface.
 	#[derive(Debug, serde::Deserialize)]
 	pub(crate) struct Interface {
 		pub(crate) name: String,
 		pub(crate) network: String,
-		pub(crate) address: String,
 		#[serde(rename = "received-bytes")]
 		pub(crate) received_bytes: u64,
+		#[serde(rename = "received-packets")]
+		pub(crate) received_packets: u64,
+		#[serde(rename = "received-errors", default)]
+		pub(crate) received_errors: u64,
+		// Input drops. Output drops are only shown with `-d`, and under the same name.
+		#[serde(rename = "dropped-packets", default)]
+		pub(crate) received_drops: u64,
 		#[serde(rename = "sent-bytes")]
 		pub(crate) sent_bytes: u64,
+		#[serde(rename = "sent-packets")]
+		pub(crate) sent_packets: u64,
+		#[serde(rename = "send-errors", default)]
+		pub(crate) sent_errors: u64,
 	}
 
 	pub(crate) fn get_interfaces(session: &ssh2::Session) -> Result<Vec<Interface>, crate::Error> {
@@ -366,12 +795,68 @@ pub(crate) mod netstat_m {
 		mbuf_statistics: MBufStatistics,
 	}
 
-	#[derive(Clone, Copy, Debug, serde::Deserialize)]
+	// Fields that are not present in the output of all FreeBSD versions are defaulted.
+	#[derive(Clone, Copy, Debug, Default, serde::Deserialize)]
 	pub(crate) struct MBufStatistics {
+		#[serde(rename = "mbuf-current")]
+		pub(crate) mbuf_current: u64,
+		#[serde(rename = "mbuf-total")]
+		pub(crate) mbuf_total: u64,
+
 		#[serde(rename = "cluster-max")]
 		pub(crate) cluster_max: u64,
 		#[serde(rename = "cluster-total")]
 		pub(crate) cluster_total: u64,
+
+		#[serde(rename = "jumbo-max", default)]
+		pub(crate) jumbo_max: u64,
+		#[serde(rename = "jumbo-total", default)]
+		pub(crate) jumbo_total: u64,
+		#[serde(rename = "jumbo9-max", default)]
+		pub(crate) jumbo9_max: u64,
+		#[serde(rename = "jumbo9-total", default)]
+		pub(crate) jumbo9_total: u64,
+		#[serde(rename = "jumbo16-max", alias = "jumbo16-limit", default)]
+		pub(crate) jumbo16_max: u64,
+		#[serde(rename = "jumbo16-total", default)]
+		pub(crate) jumbo16_total: u64,
+
+		// netstat names these `bytes-*`, but they are in KiB.
+		#[serde(rename = "bytes-in-use", default)]
+		pub(crate) kib_in_use: u64,
+		#[serde(rename = "bytes-total", default)]
+		pub(crate) kib_total: u64,
+
+		#[serde(rename = "mbuf-failures", default)]
+		pub(crate) mbuf_failures: u64,
+		#[serde(rename = "cluster-failures", default)]
+		pub(crate) cluster_failures: u64,
+		#[serde(rename = "packet-failures", default)]
+		pub(crate) packet_failures: u64,
+		#[serde(rename = "jumbop-failures", default)]
+		pub(crate) jumbop_failures: u64,
+		#[serde(rename = "jumbo9-failures", default)]
+		pub(crate) jumbo9_failures: u64,
+		#[serde(rename = "jumbo16-failures", default)]
+		pub(crate) jumbo16_failures: u64,
+
+		#[serde(rename = "mbuf-sleeps", default)]
+		pub(crate) mbuf_sleeps: u64,
+		#[serde(rename = "cluster-sleeps", default)]
+		pub(crate) cluster_sleeps: u64,
+		#[serde(rename = "packet-sleeps", default)]
+		pub(crate) packet_sleeps: u64,
+		#[serde(rename = "jumbop-sleeps", default)]
+		pub(crate) jumbop_sleeps: u64,
+		#[serde(rename = "jumbo9-sleeps", default)]
+		pub(crate) jumbo9_sleeps: u64,
+		#[serde(rename = "jumbo16-sleeps", default)]
+		pub(crate) jumbo16_sleeps: u64,
+
+		#[serde(rename = "sfbufs-alloc-failed", default)]
+		pub(crate) sfbufs_alloc_failed: u64,
+		#[serde(rename = "sfbufs-alloc-wait", default)]
+		pub(crate) sfbufs_alloc_wait: u64,
 	}
 
 	pub(crate) fn get_mbuf_statistics(session: &ssh2::Session) -> Result<MBufStatistics, crate::Error> {
@@ -380,27 +865,262 @@ pub(crate) mod netstat_m {
 	}
 }
 
+pub(crate) mod netstat_rn {
+	#[derive(Debug, serde::Deserialize)]
+	struct Output {
+		statistics: Statistics,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct Statistics {
+		#[serde(rename = "route-information")]
+		route_information: RouteInformation,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct RouteInformation {
+		#[serde(rename = "route-table")]
+		route_table: RouteTable,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct RouteTable {
+		#[serde(rename = "rt-family", default)]
+		rt_family: Vec<RtFamily>,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct RtFamily {
+		// `Internet` or `Internet6`
+		#[serde(rename = "address-family")]
+		address_family: String,
+		#[serde(rename = "rt-entry", default)]
+		rt_entry: Vec<RtEntry>,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct RtEntry {
+		destination: String,
+		gateway: String,
+		#[serde(rename = "interface-name")]
+		interface_name: String,
+	}
+
+	#[derive(Debug)]
+	pub(crate) struct DefaultRoute {
+		// `None` if the route points at an interface (`link#N`) rather than an IP, like for point-to-point links.
+		pub(crate) gateway: Option<std::net::IpAddr>,
+		pub(crate) interface: String,
+		pub(crate) is_ipv6: bool,
+	}
+
+	pub(crate) fn get_default_routes(session: &ssh2::Session) -> Result<Vec<DefaultRoute>, crate::Error> {
+		let Output { statistics: Statistics { route_information: RouteInformation { route_table: RouteTable { rt_family } } } } =
+			super::read_json(super::exec(session, "/usr/bin/netstat -rn --libxo json")?)?;
+
+		let result =
+			rt_family.into_iter()
+			.flat_map(|RtFamily { address_family, rt_entry }| {
+				let is_ipv6 = address_family == "Internet6";
+				rt_entry.into_iter().map(move |entry| (is_ipv6, entry))
+			})
+			.filter(|(_, RtEntry { destination, .. })| destination == "default")
+			.map(|(is_ipv6, RtEntry { gateway, interface_name, .. })| {
+				// IPv6 link-local gateways have a scope suffix, like `fe80::1%igb0`
+				let gateway = gateway.split('%').next().unwrap_or_default();
+				DefaultRoute {
+					gateway: gateway.parse().ok(),
+					interface: interface_name,
+					is_ipv6,
+				}
+			})
+			.collect();
+		Ok(result)
+	}
+}
+
 pub(crate) mod opnconfig {
 	pub(crate) fn run(session: &ssh2::Session) -> Result<String, crate::Error> {
 		super::read_string(super::read_file(session, std::path::Path::new("/conf/config.xml"))?)
 	}
 }
 
-pub(crate) mod pfctl_s_info {
-	pub(crate) fn get_states_used(session: &ssh2::Session) -> Result<f32, crate::Error> {
-		let states_used =
-			super::read_lines(super::exec(session, "/sbin/pfctl -s info"))
+pub(crate) mod pfctl_s_memory {
+	pub(crate) fn get_states_limit(session: &ssh2::Session) -> Result<f32, crate::Error> {
+		// states        hard limit   100000
+		// src-nodes     hard limit    10000
+		// ...
+		let states_limit =
+			super::read_lines(super::exec(session, "/sbin/pfctl -s memory"))
 			.find_map(|line| match line {
 				Ok(line) => {
-					let index = line.find("current entries")?;
-					let value = line[(index + "current entries".len())..].trim().to_owned();
+					let value = line.strip_prefix("states")?.trim_start().strip_prefix("hard limit")?.trim().to_owned();
 					Some(Ok(value))
 				},
 				Err(err) => Some(Err(err)),
 			})
-			.ok_or("could not read state table size")??
+			.ok_or("could not read state table limit")??
 			.parse()?;
-		Ok(states_used)
+		Ok(states_limit)
+	}
+}
+
+pub(crate) mod pfctl_s_info {
+	#[derive(Debug, Default)]
+	pub(crate) struct Info {
+		pub(crate) status: String,
+		pub(crate) debug: String,
+		pub(crate) current_entries: u64,
+		pub(crate) counters: Counters,
+	}
+
+	#[derive(Clone, Copy, Debug, Default)]
+	pub(crate) struct Counters {
+		pub(crate) searches: u64,
+		pub(crate) inserts: u64,
+		pub(crate) removals: u64,
+		pub(crate) r#match: u64,
+		pub(crate) bad_offset: u64,
+		pub(crate) fragment: u64,
+		pub(crate) short: u64,
+		pub(crate) normalize: u64,
+		pub(crate) memory: u64,
+		pub(crate) state_mismatch: u64,
+		pub(crate) state_limit: u64,
+	}
+
+	pub(crate) fn run(session: &ssh2::Session) -> Result<Info, crate::Error> {
+		let mut info = Info::default();
+		let mut current_entries = None;
+
+		// Status: Enabled for 0 days 15:20:47           Debug: Urgent
+		//
+		// State Table                          Total             Rate
+		//   current entries                      306
+		//   searches                        12345678          224.4/s
+		//   ...
+		// Counters
+		//   match                             234567            4.3/s
+		//   ...
+		for line in super::read_lines(super::exec(session, "/sbin/pfctl -s info")) {
+			let line = line?;
+
+			if let Some(status) = line.strip_prefix("Status:") {
+				let (status, debug) = status.split_once("Debug:").unwrap_or((status, ""));
+				status.split_whitespace().next().unwrap_or_default().clone_into(&mut info.status);
+				debug.trim().clone_into(&mut info.debug);
+				continue;
+			}
+
+			// The counter name can contain spaces, so it is everything up to the first number.
+			let mut name = String::new();
+			let mut value = None;
+			for part in line.split_whitespace() {
+				if let Ok(part) = part.parse::<u64>() {
+					value = Some(part);
+					break;
+				}
+
+				if !name.is_empty() {
+					name.push(' ');
+				}
+				name.push_str(part);
+			}
+			let Some(value) = value else { continue; };
+
+			let counter = match &*name {
+				"current entries" => { current_entries = Some(value); continue; },
+				"searches" => &mut info.counters.searches,
+				"inserts" => &mut info.counters.inserts,
+				"removals" => &mut info.counters.removals,
+				"match" => &mut info.counters.r#match,
+				"bad-offset" => &mut info.counters.bad_offset,
+				"fragment" => &mut info.counters.fragment,
+				"short" => &mut info.counters.short,
+				"normalize" => &mut info.counters.normalize,
+				"memory" => &mut info.counters.memory,
+				"state-mismatch" => &mut info.counters.state_mismatch,
+				"state-limit" => &mut info.counters.state_limit,
+				_ => continue,
+			};
+			*counter = value;
+		}
+
+		info.current_entries = current_entries.ok_or("could not read state table size")?;
+
+		Ok(info)
+	}
+}
+
+pub(crate) mod pfctl_s_state {
+	#[derive(Debug)]
+	pub(crate) struct State {
+		pub(crate) interface: String,
+		pub(crate) protocol: String,
+		pub(crate) source: std::net::IpAddr,
+		pub(crate) destination: std::net::IpAddr,
+		pub(crate) destination_port: Option<u16>,
+	}
+
+	pub(crate) fn run(session: &ssh2::Session) -> impl Iterator<Item = Result<State, crate::Error>> {
+		// all tcp 192.168.1.10:54321 -> 1.2.3.4:443       ESTABLISHED:ESTABLISHED
+		// all tcp 203.0.113.5:6000 (192.168.1.10:54321) -> 1.2.3.4:443       ESTABLISHED:ESTABLISHED
+		// em0 tcp 192.168.1.1:22 <- 1.2.3.4:50000       ESTABLISHED:ESTABLISHED
+		// all udp 2001:db8::1[53] <- 2001:db8::2[12345]       MULTIPLE:SINGLE
+		//
+		// A parenthesized address is the original address before NAT, and is preferred over the translated address.
+		super::read_lines(super::exec(session, "/sbin/pfctl -s state"))
+			.map(|line| -> Result<_, crate::Error> {
+				let line = line?;
+
+				let mut line_parts = line.split_whitespace().peekable();
+
+				let interface = line_parts.next().ok_or("pfctl state output is malformed")?;
+				let protocol = line_parts.next().ok_or("pfctl state output is malformed")?;
+
+				let mut left = line_parts.next().ok_or("pfctl state output is malformed")?;
+				if let Some(original) = line_parts.next_if(|part| part.starts_with('(')) {
+					left = original.trim_start_matches('(').trim_end_matches(')');
+				}
+
+				let direction = line_parts.next().ok_or("pfctl state output is malformed")?;
+
+				let mut right = line_parts.next().ok_or("pfctl state output is malformed")?;
+				if let Some(original) = line_parts.next_if(|part| part.starts_with('(')) {
+					right = original.trim_start_matches('(').trim_end_matches(')');
+				}
+
+				let (source, destination) = match direction {
+					"->" => (left, right),
+					"<-" => (right, left),
+					direction => return Err(format!("pfctl state output has unexpected direction {direction:?}").into()),
+				};
+
+				let (source, _) = parse_host(source)?;
+				let (destination, destination_port) = parse_host(destination)?;
+
+				Ok(State {
+					interface: interface.to_owned(),
+					protocol: protocol.to_owned(),
+					source,
+					destination,
+					destination_port,
+				})
+			})
+	}
+
+	fn parse_host(s: &str) -> Result<(std::net::IpAddr, Option<u16>), crate::Error> {
+		// IPv6 addresses have the port in brackets, IPv4 addresses have it after a colon.
+		if let Some((addr, port)) = s.strip_suffix(']').and_then(|s| s.split_once('[')) {
+			Ok((addr.parse()?, Some(port.parse()?)))
+		}
+		else if let Ok(addr) = s.parse() {
+			Ok((addr, None))
+		}
+		else {
+			let (addr, port) = s.rsplit_once(':').ok_or_else(|| format!("pfctl state output has malformed host {s:?}"))?;
+			Ok((addr.parse()?, Some(port.parse()?)))
+		}
 	}
 }
 
@@ -443,17 +1163,110 @@ pub(crate) mod smartctl_a {
 			}
 		}
 
-		pub(crate) fn run(&self, session: &ssh2::Session) -> Result<(bool, crate::c_uint), crate::Error> {
-			let Output { smart_status: SmartStatus { passed }, temperature: Temperature { current } } =
-				super::read_json(super::exec(session, &self.command)?)?;
-			Ok((passed, current))
+		// smartctl's exit code is a bitmask of problems it found, but it writes valid JSON regardless,
+		// so a disk that can't be queried (like a USB stick without SAT passthrough) just has fewer fields.
+		pub(crate) fn run(&self, session: &ssh2::Session) -> Result<Smart, crate::Error> {
+			let Output {
+				smart_status,
+				temperature,
+				power_on_time,
+				ata_smart_attributes,
+				nvme_smart_health_information_log,
+				ata_smart_self_test_log,
+				nvme_self_test_log,
+			} = super::read_json(super::exec(session, &self.command)?)?;
+
+			let mut health = Health {
+				power_on_hours: power_on_time.map(|PowerOnTime { hours }| hours),
+				..Default::default()
+			};
+
+			for AtaSmartAttribute { id, name, value, raw: AtaSmartAttributeRaw { value: raw_value } } in ata_smart_attributes.table {
+				match (id, &*name) {
+					(5, _) => health.reallocated_sectors = Some(raw_value),
+					(197, _) => health.pending_sectors = Some(raw_value),
+					(198, _) => health.uncorrectable_sectors = Some(raw_value),
+
+					// SSD wear attributes, where the normalized value is the percentage of life remaining.
+					// The IDs of these are vendor-specific and mean other things on other drives, so they're matched by the name
+					// that smartctl's drive database gives them.
+					(_, "Media_Wearout_Indicator" | "Percent_Lifetime_Remain" | "SSD_Life_Left" | "Wear_Leveling_Count") =>
+						health.percentage_used = Some(100_u64.saturating_sub(value)),
+
+					_ => (),
+				}
+			}
+
+			if let Some(NvmeSmartHealthInformationLog { critical_warning, available_spare, available_spare_threshold, percentage_used, media_errors }) =
+				nvme_smart_health_information_log
+			{
+				health.critical_warning = Some(critical_warning);
+				health.available_spare = Some((available_spare, available_spare_threshold));
+				health.percentage_used = Some(percentage_used);
+				health.media_errors = Some(media_errors);
+			}
+
+			let self_test =
+				ata_smart_self_test_log
+				.and_then(|AtaSmartSelfTestLog { standard: AtaSmartSelfTestLogStandard { table } }| table.into_iter().next())
+				.map(|AtaSmartSelfTest { status: AtaSmartSelfTestStatus { string, passed } }| SelfTest { result: string, passed })
+				.or_else(||
+					nvme_self_test_log
+					.and_then(|NvmeSelfTestLog { table }| table.into_iter().next())
+					.map(|NvmeSelfTest { self_test_result: NvmeSelfTestResult { value, string } }| SelfTest { result: string, passed: value == 0 }));
+
+			Ok(Smart {
+				passed: smart_status.map(|SmartStatus { passed }| passed),
+				temperature: temperature.map(|Temperature { current }| current),
+				health,
+				self_test,
+			})
 		}
 	}
 
-	#[derive(Clone, Copy, Debug, Default, serde::Deserialize)]
+	#[derive(Debug)]
+	pub(crate) struct Smart {
+		pub(crate) passed: Option<bool>,
+		pub(crate) temperature: Option<crate::c_uint>,
+		pub(crate) health: Health,
+		pub(crate) self_test: Option<SelfTest>,
+	}
+
+	// Each field is only set if the disk reports it.
+	#[derive(Clone, Copy, Debug, Default)]
+	pub(crate) struct Health {
+		pub(crate) power_on_hours: Option<u64>,
+		pub(crate) reallocated_sectors: Option<u64>,
+		pub(crate) pending_sectors: Option<u64>,
+		pub(crate) uncorrectable_sectors: Option<u64>,
+		pub(crate) media_errors: Option<u64>,
+		pub(crate) percentage_used: Option<u64>,
+		pub(crate) critical_warning: Option<u64>,
+		pub(crate) available_spare: Option<(u64, u64)>,
+	}
+
+	#[derive(Debug)]
+	pub(crate) struct SelfTest {
+		pub(crate) result: String,
+		pub(crate) passed: bool,
+	}
+
+	#[derive(Debug, Default, serde::Deserialize)]
 	struct Output {
-		smart_status: SmartStatus,
-		temperature: Temperature,
+		#[serde(default)]
+		smart_status: Option<SmartStatus>,
+		#[serde(default)]
+		temperature: Option<Temperature>,
+		#[serde(default)]
+		power_on_time: Option<PowerOnTime>,
+		#[serde(default)]
+		ata_smart_attributes: AtaSmartAttributes,
+		#[serde(default)]
+		nvme_smart_health_information_log: Option<NvmeSmartHealthInformationLog>,
+		#[serde(default)]
+		ata_smart_self_test_log: Option<AtaSmartSelfTestLog>,
+		#[serde(default)]
+		nvme_self_test_log: Option<NvmeSelfTestLog>,
 	}
 
 	#[derive(Clone, Copy, Debug, Default, serde::Deserialize)]
@@ -465,20 +1278,113 @@ pub(crate) mod smartctl_a {
 	struct Temperature {
 		current: crate::c_uint,
 	}
+
+	#[derive(Clone, Copy, Debug, serde::Deserialize)]
+	struct PowerOnTime {
+		hours: u64,
+	}
+
+	#[derive(Debug, Default, serde::Deserialize)]
+	struct AtaSmartAttributes {
+		table: Vec<AtaSmartAttribute>,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct AtaSmartAttribute {
+		id: u8,
+		name: String,
+		value: u64,
+		raw: AtaSmartAttributeRaw,
+	}
+
+	#[derive(Clone, Copy, Debug, serde::Deserialize)]
+	struct AtaSmartAttributeRaw {
+		value: u64,
+	}
+
+	#[derive(Clone, Copy, Debug, serde::Deserialize)]
+	struct NvmeSmartHealthInformationLog {
+		critical_warning: u64,
+		available_spare: u64,
+		available_spare_threshold: u64,
+		percentage_used: u64,
+		media_errors: u64,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct AtaSmartSelfTestLog {
+		standard: AtaSmartSelfTestLogStandard,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct AtaSmartSelfTestLogStandard {
+		#[serde(default)]
+		table: Vec<AtaSmartSelfTest>,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct AtaSmartSelfTest {
+		status: AtaSmartSelfTestStatus,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct AtaSmartSelfTestStatus {
+		string: String,
+		#[serde(default)]
+		passed: bool,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct NvmeSelfTestLog {
+		#[serde(default)]
+		table: Vec<NvmeSelfTest>,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct NvmeSelfTest {
+		self_test_result: NvmeSelfTestResult,
+	}
+
+	#[derive(Debug, serde::Deserialize)]
+	struct NvmeSelfTestResult {
+		value: u64,
+		string: String,
+	}
 }
 
 pub(crate) mod smartctl_i {
 	#[derive(Debug, serde::Deserialize)]
 	struct Output {
-		serial_number: String,
+		#[serde(default)]
+		serial_number: Option<String>,
 	}
 
-	pub(crate) fn get_serial_number(name: &str, session: &ssh2::Session) -> Result<String, crate::Error> {
+	pub(crate) fn get_serial_number(name: &str, session: &ssh2::Session) -> Result<Option<String>, crate::Error> {
 		let Output { serial_number } = super::read_json(super::exec(session, &format!("/usr/local/sbin/smartctl -i --json=c '/dev/{name}'"))?)?;
 		Ok(serial_number)
 	}
 }
 
+#[allow(non_snake_case)]
+pub(crate) mod sysctl_F {
+	pub(crate) fn run(names: &[impl AsRef<str>], session: &ssh2::Session) -> impl Iterator<Item = Result<(String, String), crate::Error>> {
+		use std::fmt::Write;
+
+		let mut command = "/sbin/sysctl -F".to_owned();
+		for name in names {
+			write!(command, " '{}'", name.as_ref()).unwrap();
+		}
+
+		// dev.cpu.0.temperature: IK
+		super::read_lines(super::exec(session, &command))
+			.map(|line| -> Result<_, crate::Error> {
+				let line = line?;
+				let (name, format) = line.split_once(": ").ok_or("sysctl -F output is malformed")?;
+				Ok((name.to_owned(), format.to_owned()))
+			})
+	}
+}
+
 #[allow(non_snake_case)]
 pub(crate) mod sysctl_aN {
 	pub(crate) fn run(session: &ssh2::Session) -> impl Iterator<Item = Result<String, crate::Error>> {
@@ -486,6 +1392,42 @@ pub(crate) mod sysctl_aN {
 	}
 }
 
+pub(crate) mod swapinfo_k {
+	pub(crate) fn get_swap(session: &ssh2::Session) -> Result<crate::memory::Swap, crate::Error> {
+		let mut swap = crate::memory::Swap::default();
+
+		// Device          1K-blocks     Used    Avail Capacity
+		// /dev/ada0p3       2097152        0  2097152     0%
+		//
+		// If there is more than one device, there is also a "Total" line at the end, which is skipped.
+		for line in super::read_lines(super::exec(session, "/usr/sbin/swapinfo -k")).skip(1) {
+			let line = line?;
+
+			let mut line_parts = line.split_whitespace();
+
+			let device = line_parts.next().ok_or("swapinfo output is malformed")?;
+			if device == "Total" {
+				continue;
+			}
+
+			let total: u64 = line_parts.next().ok_or("swapinfo output is malformed")?.parse()?;
+			let used: u64 = line_parts.next().ok_or("swapinfo output is malformed")?.parse()?;
+
+			swap.total += total;
+			swap.used += used;
+		}
+
+		Ok(swap)
+	}
+}
+
+pub(crate) mod sysctl_nvd_parent {
+	pub(crate) fn run(unit: &str, session: &ssh2::Session) -> Result<Option<String>, crate::Error> {
+		let parent = super::read_line(super::exec(session, &format!("/sbin/sysctl -n 'dev.nvd.{unit}.%parent'")))?;
+		Ok((!parent.is_empty()).then_some(parent))
+	}
+}
+
 pub(crate) mod sysctl_kern_disks {
 	pub(crate) fn run(session: &ssh2::Session) -> Result<String, crate::Error> {
 		super::read_line(super::exec(session, "/sbin/sysctl -n kern.disks"))
@@ -512,6 +1454,182 @@ pub(crate) mod version {
 	}
 }
 
+pub(crate) mod zpool_list {
+	#[derive(Debug)]
+	pub(crate) struct Pool {
+		pub(crate) name: String,
+		pub(crate) health: String,
+		pub(crate) size: u64,
+		pub(crate) capacity: u64,
+		pub(crate) fragmentation: Option<u64>,
+	}
+
+	pub(crate) fn run(session: &ssh2::Session) -> impl Iterator<Item = Result<Pool, crate::Error>> {
+		// zroot	ONLINE	214748364800	10	5
+		super::read_lines(super::exec(session, "/sbin/zpool list -Hp -o name,health,size,capacity,fragmentation"))
+			.map(|line| -> Result<_, crate::Error> {
+				let line = line?;
+
+				let mut line_parts = line.split('\t');
+
+				let name = line_parts.next().ok_or("zpool list output is malformed")?;
+				let health = line_parts.next().ok_or("zpool list output is malformed")?;
+				let size = line_parts.next().ok_or("zpool list output is malformed")?.parse()?;
+				let capacity = line_parts.next().ok_or("zpool list output is malformed")?.parse()?;
+
+				// Pools without the spacemap_histogram feature report fragmentation as `-`
+				let fragmentation = line_parts.next().ok_or("zpool list output is malformed")?;
+				let fragmentation = if fragmentation == "-" { None } else { Some(fragmentation.parse()?) };
+
+				Ok(Pool {
+					name: name.to_owned(),
+					health: health.to_owned(),
+					size,
+					capacity,
+					fragmentation,
+				})
+			})
+	}
+}
+
+pub(crate) mod zpool_status {
+	#[derive(Debug, Default)]
+	pub(crate) struct Pool {
+		pub(crate) name: String,
+		pub(crate) scan: String,
+		pub(crate) vdevs: Vec<Vdev>,
+	}
+
+	#[derive(Debug)]
+	pub(crate) struct Vdev {
+		pub(crate) name: String,
+
+		// Nesting level under the pool, like 1 for top-level vdevs like `mirror-0` and 2 for the disks in them.
+		// Group headers like `logs`, `cache` and `spares` are at 0, the same level as the pool itself.
+		pub(crate) depth: usize,
+
+		// None for group headers.
+		pub(crate) state: Option<String>,
+
+		// None for group headers and spares.
+		pub(crate) errors: Option<VdevErrors>,
+	}
+
+	#[derive(Clone, Copy, Debug)]
+	pub(crate) struct VdevErrors {
+		pub(crate) read: u64,
+		pub(crate) write: u64,
+		pub(crate) checksum: u64,
+	}
+
+	pub(crate) fn run(session: &ssh2::Session) -> Result<Vec<Pool>, crate::Error> {
+		//   pool: zroot
+		//  state: ONLINE
+		//   scan: scrub in progress since Sun Jan 23 03:00:10 2022
+		// 	1.23G scanned at 100M/s, 500M issued at 50M/s, 10G total
+		// 	0B repaired, 5.00% done, 00:03:00 to go
+		// config:
+		//
+		// 	NAME        STATE     READ WRITE CKSUM
+		// 	zroot       ONLINE       0     0     0
+		// 	  mirror-0  ONLINE       0     0     0
+		// 	    ada0p4  ONLINE       0     0     0
+		// 	    ada1p4  ONLINE       0     0     0
+		// 	logs
+		// 	  ada2p1    ONLINE       0     0     0
+		// 	spares
+		// 	  ada3p4    AVAIL
+		//
+		// errors: No known data errors
+
+		#[derive(Clone, Copy)]
+		enum Section {
+			Header,
+			Scan,
+			Config,
+		}
+
+		let mut result: Vec<Pool> = vec![];
+		let mut section = Section::Header;
+
+		for line in super::read_lines(super::exec(session, "/sbin/zpool status -p")) {
+			let line = line?;
+			let trimmed_line = line.trim();
+
+			if let Some(name) = trimmed_line.strip_prefix("pool:") {
+				result.push(Pool {
+					name: name.trim().to_owned(),
+					..Default::default()
+				});
+				section = Section::Header;
+				continue;
+			}
+
+			let Some(pool) = result.last_mut() else { continue; };
+
+			if let Some(scan) = trimmed_line.strip_prefix("scan:") {
+				scan.trim().clone_into(&mut pool.scan);
+				section = Section::Scan;
+				continue;
+			}
+
+			if trimmed_line.starts_with("config:") {
+				section = Section::Config;
+				continue;
+			}
+
+			if trimmed_line.starts_with("errors:") {
+				section = Section::Header;
+				continue;
+			}
+
+			match section {
+				Section::Header => (),
+
+				// Only the progress of an in-progress scrub or resilver is interesting from the continuation lines.
+				Section::Scan =>
+					if let Some((_, progress)) = trimmed_line.split_once(", ").filter(|_| trimmed_line.contains("% done")) {
+						pool.scan.push_str(", ");
+						pool.scan.push_str(progress);
+					},
+
+				Section::Config => {
+					// Each level of nesting is indented by two spaces after the leading tab.
+					let indented_line = line.trim_start_matches('\t');
+					let depth = (indented_line.len() - indented_line.trim_start_matches(' ').len()) / 2;
+
+					let mut line_parts = trimmed_line.split_whitespace();
+					let Some(name) = line_parts.next() else { continue; };
+
+					// Skip the header and the line for the pool itself
+					if name == "NAME" || (depth == 0 && name == pool.name) {
+						continue;
+					}
+
+					let state = line_parts.next();
+
+					let errors = match (line_parts.next(), line_parts.next(), line_parts.next()) {
+						(Some(read), Some(write), Some(checksum)) => match (read.parse(), write.parse(), checksum.parse()) {
+							(Ok(read), Ok(write), Ok(checksum)) => Some(VdevErrors { read, write, checksum }),
+							_ => None,
+						},
+						_ => None,
+					};
+
+					pool.vdevs.push(Vdev {
+						name: name.to_owned(),
+						depth,
+						state: state.map(ToOwned::to_owned),
+						errors,
+					});
+				},
+			}
+		}
+
+		Ok(result)
+	}
+}
+
 fn read_file(session: &ssh2::Session, path: &std::path::Path) -> Result<ssh2::Channel, crate::Error> {
 	let (channel, _) = session.scp_recv(path)?;
 	Ok(channel)
@@ -0,0 +1,69 @@
+#[derive(Debug)]
+pub(crate) struct StateHolders {
+	count: usize,
+	refresh_interval: std::time::Duration,
+	last_refresh: Option<std::time::Instant>,
+
+	pub(crate) sources: Vec<(String, usize)>,
+	pub(crate) destinations: Vec<(String, usize)>,
+	pub(crate) destination_ports: Vec<(String, usize)>,
+	pub(crate) interfaces: Vec<(String, usize)>,
+}
+
+impl StateHolders {
+	pub(crate) fn new(crate::config::StateHolders { count, refresh_interval }: crate::config::StateHolders) -> Self {
+		StateHolders {
+			count,
+			refresh_interval: std::time::Duration::from_secs(refresh_interval),
+			last_refresh: None,
+
+			sources: vec![],
+			destinations: vec![],
+			destination_ports: vec![],
+			interfaces: vec![],
+		}
+	}
+
+	pub(crate) fn count(&self) -> usize {
+		self.count
+	}
+
+	pub(crate) fn update(&mut self, session: &ssh2::Session) -> Result<(), crate::Error> {
+		// The state table can be large, so it is fetched less often than everything else.
+		let now = std::time::Instant::now();
+		if self.last_refresh.is_some_and(|last_refresh| now.duration_since(last_refresh) < self.refresh_interval) {
+			return Ok(());
+		}
+		self.last_refresh = Some(now);
+
+		let mut sources: std::collections::HashMap<_, usize> = Default::default();
+		let mut destinations: std::collections::HashMap<_, usize> = Default::default();
+		let mut destination_ports: std::collections::HashMap<_, usize> = Default::default();
+		let mut interfaces: std::collections::HashMap<_, usize> = Default::default();
+
+		for state in crate::ssh_exec::pfctl_s_state::run(session) {
+			let crate::ssh_exec::pfctl_s_state::State { interface, protocol, source, destination, destination_port } = state?;
+
+			*sources.entry(source.to_string()).or_default() += 1;
+			*destinations.entry(destination.to_string()).or_default() += 1;
+			if let Some(destination_port) = destination_port {
+				*destination_ports.entry(format!("{destination_port}/{protocol}")).or_default() += 1;
+			}
+			*interfaces.entry(interface).or_default() += 1;
+		}
+
+		self.sources = top(sources, self.count);
+		self.destinations = top(destinations, self.count);
+		self.destination_ports = top(destination_ports, self.count);
+		self.interfaces = top(interfaces, self.count);
+
+		Ok(())
+	}
+}
+
+fn top(counts: std::collections::HashMap<String, usize>, n: usize) -> Vec<(String, usize)> {
+	let mut counts: Vec<_> = counts.into_iter().collect();
+	counts.sort_by(|(key1, count1), (key2, count2)| count2.cmp(count1).then_with(|| key1.cmp(key2)));
+	counts.truncate(n);
+	counts
+}
@@ -1,26 +1,48 @@
 #[derive(Debug)]
 pub(crate) struct TemperatureSysctl {
 	pub(crate) name: String,
-	pub(crate) value: crate::c_uint,
+
+	// The number of decimal digits of a Kelvin value, from the sysctl's `IK<n>` format.
+	// None if the sysctl's format is a plain `I`, which drivers use for values that are already in degrees Celsius.
+	precision: Option<u32>,
+
+	pub(crate) value: crate::c_int,
 }
 
 impl TemperatureSysctl {
-	pub(crate) fn get_all(session: &ssh2::Session) -> Result<Box<[Self]>, crate::Error> {
+	pub(crate) fn get_all(sysctl_names: &[String], filter: &crate::config::Filter, session: &ssh2::Session) -> Result<Box<[Self]>, crate::Error> {
+		let names: Vec<_> = sysctl_names.iter().filter(|sysctl_name| filter.matches(sysctl_name)).collect();
+		if names.is_empty() {
+			return Ok(Default::default());
+		}
+
 		let result: Result<Box<[_]>, crate::Error> =
-			crate::ssh_exec::sysctl_aN::run(session)
-			.filter_map(|sysctl_name| match sysctl_name {
-				Ok(sysctl_name) =>
-					if sysctl_name.contains("temperature") {
-						Some(Ok(TemperatureSysctl {
-							name: sysctl_name,
-							value: 0,
-						}))
-					}
-					else {
-						None
+			crate::ssh_exec::sysctl_F::run(&names, session)
+			.filter_map(|sysctl_format| {
+				let (name, format) = match sysctl_format {
+					Ok(sysctl_format) => sysctl_format,
+					Err(err) => return Some(Err(err)),
+				};
+
+				// `IK` is deci-Kelvin, `IK<n>` is Kelvin with n decimal digits.
+				//
+				// Sysctls of any other format are skipped, since their values are read from the same stream as the other batched sysctls
+				// as 4-byte ints, so a value of any other size would misalign all the values after it.
+				let precision = match (&*format, format.strip_prefix("IK")) {
+					("I", _) => None,
+					(_, Some("")) => Some(1),
+					(_, Some(precision)) => match precision.parse::<u32>() {
+						Ok(precision) => Some(precision),
+						Err(err) => return Some(Err(err.into())),
 					},
+					(_, None) => return None,
+				};
 
-				Err(err) => Some(Err(err)),
+				Some(Ok(TemperatureSysctl {
+					name,
+					precision,
+					value: 0,
+				}))
 			})
 			.collect();
 		let mut result = result?;
@@ -32,4 +54,12 @@ impl TemperatureSysctl {
 		self.value = crate::Parse::parse(reader)?;
 		Ok(())
 	}
+
+	pub(crate) fn celsius(&self) -> f32 {
+		match self.precision {
+			#[allow(clippy::cast_possible_wrap)] // precision is a small number of digits
+			Some(precision) => self.value as f32 / 10_f32.powi(precision as i32) - 273.15,
+			None => self.value as f32,
+		}
+	}
 }
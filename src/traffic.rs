@@ -0,0 +1,135 @@
+// The router's date is only needed to notice when the day or billing month changes, so it is fetched occasionally.
+const DATE_REFRESH_INTERVAL_SECS: u64 = 60;
+
+// The totals are saved periodically rather than on every update, so at most this much accounting is lost if the dashboard is killed.
+const SAVE_INTERVAL_SECS: u64 = 60;
+
+#[derive(Debug)]
+pub(crate) struct Traffic {
+	billing_day: u32,
+	interfaces: std::collections::BTreeMap<String, crate::config::TrafficInterface>,
+
+	path: std::path::PathBuf,
+	totals: std::collections::BTreeMap<String, Totals>,
+
+	date: (u32, u32, u32),
+	last_date_refresh: std::time::Instant,
+	last_save: std::time::Instant,
+}
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+pub(crate) struct Totals {
+	// YYYY-MM-DD
+	day: String,
+	pub(crate) day_received: u64,
+	pub(crate) day_sent: u64,
+
+	// YYYY-MM of the start of the billing month
+	month: String,
+	pub(crate) month_received: u64,
+	pub(crate) month_sent: u64,
+}
+
+impl Traffic {
+	pub(crate) fn new(
+		crate::config::Traffic { billing_day, interfaces }: crate::config::Traffic,
+		shown_interfaces: &crate::interface::Interfaces,
+		session: &ssh2::Session,
+	) -> Result<Self, crate::Error> {
+		// Counters are only collected for the interfaces that are shown, so a hidden interface would silently never accumulate any traffic.
+		if let Some(name) = interfaces.keys().find(|name| shown_interfaces.get(name).is_none()) {
+			return Err(format!("traffic interface {name} is not an assigned interface, or is disabled and hidden by `interfaces.disabled: hide`").into());
+		}
+
+		let mut path = dirs::data_local_dir().ok_or("local data dir not defined")?;
+		path.push("opnsense-dashboard");
+		path.push("traffic.json");
+
+		let mut totals: std::collections::BTreeMap<String, Totals> = match std::fs::File::open(&path) {
+			Ok(f) => serde_json::from_reader(std::io::BufReader::new(f)).map_err(|err| format!("could not parse {}: {err}", path.display()))?,
+			Err(err) if err.kind() == std::io::ErrorKind::NotFound => Default::default(),
+			Err(err) => return Err(format!("could not open {}: {err}", path.display()).into()),
+		};
+		for name in interfaces.keys() {
+			totals.entry(name.clone()).or_default();
+		}
+
+		let now = std::time::Instant::now();
+
+		Ok(Traffic {
+			// Billing months start on the same day every month, so the day must exist in every month.
+			billing_day: billing_day.clamp(1, 28),
+			interfaces,
+
+			path,
+			totals,
+
+			date: crate::ssh_exec::date::get_date(session)?,
+			last_date_refresh: now,
+			last_save: now,
+		})
+	}
+
+	pub(crate) fn iter(&self) -> impl Iterator<Item = (&'_ str, &'_ Totals, &'_ crate::config::TrafficInterface)> {
+		self.interfaces.iter().filter_map(|(name, interface)| Some((&**name, self.totals.get(name)?, interface)))
+	}
+
+	pub(crate) fn update(&mut self, interfaces: &crate::interface::Interfaces, session: &ssh2::Session) -> Result<(), crate::Error> {
+		let now = std::time::Instant::now();
+
+		if now.duration_since(self.last_date_refresh).as_secs() >= DATE_REFRESH_INTERVAL_SECS {
+			self.date = crate::ssh_exec::date::get_date(session)?;
+			self.last_date_refresh = now;
+		}
+
+		let (year, month, day) = self.date;
+		let current_day = format!("{year:04}-{month:02}-{day:02}");
+		let (billing_year, billing_month) =
+			if day >= self.billing_day { (year, month) }
+			else if month == 1 { (year - 1, 12) }
+			else { (year, month - 1) };
+		let current_month = format!("{billing_year:04}-{billing_month:02}");
+
+		for (name, totals) in &mut self.totals {
+			if totals.day != current_day {
+				totals.day.clone_from(&current_day);
+				totals.day_received = 0;
+				totals.day_sent = 0;
+			}
+
+			if totals.month != current_month {
+				totals.month.clone_from(&current_month);
+				totals.month_received = 0;
+				totals.month_sent = 0;
+			}
+
+			if let Some((received, sent)) = interfaces.get(name).and_then(crate::interface::Interface::transferred_bytes) {
+				totals.day_received += received;
+				totals.day_sent += sent;
+				totals.month_received += received;
+				totals.month_sent += sent;
+			}
+		}
+
+		if now.duration_since(self.last_save).as_secs() >= SAVE_INTERVAL_SECS {
+			self.save()?;
+			self.last_save = now;
+		}
+
+		Ok(())
+	}
+
+	fn save(&self) -> Result<(), crate::Error> {
+		if let Some(parent) = self.path.parent() {
+			std::fs::create_dir_all(parent)?;
+		}
+
+		// Write to a temporary file and rename it over the original, so that the totals are not lost if the dashboard is killed while saving.
+		let temp_path = self.path.with_extension("json.tmp");
+		let f = std::fs::File::create(&temp_path)?;
+		serde_json::to_writer(std::io::BufWriter::new(f), &self.totals)?;
+		std::fs::rename(&temp_path, &self.path)?;
+
+		Ok(())
+	}
+}
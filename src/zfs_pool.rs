@@ -0,0 +1,46 @@
+#[derive(Debug)]
+pub(crate) struct ZfsPool {
+	pub(crate) name: String,
+	pub(crate) health: String,
+	pub(crate) size: u64,
+	pub(crate) capacity: u64,
+	pub(crate) fragmentation: Option<u64>,
+	pub(crate) scan: String,
+	pub(crate) vdevs: Vec<crate::ssh_exec::zpool_status::Vdev>,
+}
+
+impl ZfsPool {
+	pub(crate) fn get_all(session: &ssh2::Session) -> Result<Vec<Self>, crate::Error> {
+		let mut statuses = crate::ssh_exec::zpool_status::run(session)?;
+
+		let result: Result<Vec<_>, crate::Error> =
+			crate::ssh_exec::zpool_list::run(session)
+			.map(|pool| {
+				let crate::ssh_exec::zpool_list::Pool { name, health, size, capacity, fragmentation } = pool?;
+
+				let (scan, vdevs) =
+					statuses.iter_mut()
+					.find(|status| status.name == name)
+					.map(|status| (std::mem::take(&mut status.scan), std::mem::take(&mut status.vdevs)))
+					.unwrap_or_default();
+
+				Ok(ZfsPool {
+					name,
+					health,
+					size,
+					capacity,
+					fragmentation,
+					scan,
+					vdevs,
+				})
+			})
+			.collect();
+		let mut result = result?;
+		result.sort_by(|pool1, pool2| pool1.name.cmp(&pool2.name));
+		Ok(result)
+	}
+
+	pub(crate) fn is_online(&self) -> bool {
+		self.health == "ONLINE"
+	}
+}